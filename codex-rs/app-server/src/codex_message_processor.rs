@@ -1082,6 +1082,15 @@ impl CodexMessageProcessor {
             with_escalated_permissions: None,
             justification: None,
             arg0: None,
+            require_cwd_in_writable_roots: false,
+            allocate_pty: false,
+            capture_output_lines: false,
+            stop_on_first_stdout_byte: false,
+            stdin: None,
+            merge_stderr_into_stdout: false,
+            umask: None,
+            program_policy: self.config.program_policy.clone(),
+            env_policy: self.config.shell_environment_policy.clone(),
         };
 
         let effective_policy = params
@@ -1108,6 +1117,7 @@ impl CodexMessageProcessor {
                 sandbox_cwd.as_path(),
                 &codex_linux_sandbox_exe,
                 None,
+                None,
             )
             .await
             {
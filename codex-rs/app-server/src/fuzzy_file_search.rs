@@ -47,6 +47,7 @@ pub(crate) async fn run_fuzzy_file_search(
                 cancel_flag,
                 COMPUTE_INDICES,
                 true,
+                false,
             ) {
                 Ok(res) => Ok((root, res)),
                 Err(err) => Err((root, err)),
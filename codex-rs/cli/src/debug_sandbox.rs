@@ -208,6 +208,7 @@ async fn run_command_under_sandbox(
                 sandbox_policy_cwd.as_path(),
                 stdio_policy,
                 env,
+                &config.shell_environment_policy,
             )
             .await?
         }
@@ -224,6 +225,7 @@ async fn run_command_under_sandbox(
                 sandbox_policy_cwd.as_path(),
                 stdio_policy,
                 env,
+                &config.shell_environment_policy,
             )
             .await?
         }
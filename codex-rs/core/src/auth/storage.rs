@@ -77,11 +77,14 @@ impl FileAuthStorage {
 
     /// Attempt to read and refresh the `auth.json` file in the given `CODEX_HOME` directory.
     /// Returns the full AuthDotJson structure after refreshing if necessary.
+    /// Contents that do not parse, including truncated files, are reported as
+    /// [`std::io::ErrorKind::InvalidData`].
     pub(super) fn try_read_auth_json(&self, auth_file: &Path) -> std::io::Result<AuthDotJson> {
         let mut file = File::open(auth_file)?;
         let mut contents = String::new();
         file.read_to_string(&mut contents)?;
-        let auth_dot_json: AuthDotJson = serde_json::from_str(&contents)?;
+        let auth_dot_json: AuthDotJson = serde_json::from_str(&contents)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
 
         Ok(auth_dot_json)
     }
@@ -155,9 +158,10 @@ impl KeyringAuthStorage {
     fn load_from_keyring(&self, key: &str) -> std::io::Result<Option<AuthDotJson>> {
         match self.keyring_store.load(KEYRING_SERVICE, key) {
             Ok(Some(serialized)) => serde_json::from_str(&serialized).map(Some).map_err(|err| {
-                std::io::Error::other(format!(
-                    "failed to deserialize CLI auth from keyring: {err}"
-                ))
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("failed to deserialize CLI auth from keyring: {err}"),
+                )
             }),
             Ok(None) => Ok(None),
             Err(error) => Err(std::io::Error::other(format!(
@@ -309,6 +313,19 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn file_storage_load_reports_truncated_auth_as_invalid_data() -> anyhow::Result<()> {
+        let codex_home = tempdir()?;
+        std::fs::write(get_auth_file(codex_home.path()), r#"{"OPENAI_API_KEY":"#)?;
+        let storage = FileAuthStorage::new(codex_home.path().to_path_buf());
+
+        let err = storage
+            .load()
+            .expect_err("truncated auth.json should not load");
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn file_storage_save_persists_auth_dot_json() -> anyhow::Result<()> {
         let codex_home = tempdir()?;
@@ -459,6 +476,24 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn keyring_auth_storage_load_reports_unparseable_auth_as_invalid_data() -> anyhow::Result<()> {
+        let codex_home = tempdir()?;
+        let mock_keyring = MockKeyringStore::default();
+        let key = compute_store_key(codex_home.path())?;
+        mock_keyring.save(KEYRING_SERVICE, &key, "not json")?;
+        let storage = KeyringAuthStorage::new(
+            codex_home.path().to_path_buf(),
+            Arc::new(mock_keyring.clone()),
+        );
+
+        let err = storage
+            .load()
+            .expect_err("unparseable keyring entry should not load");
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        Ok(())
+    }
+
     #[test]
     fn keyring_auth_storage_compute_store_key_for_home_directory() -> anyhow::Result<()> {
         let codex_home = PathBuf::from("~/.codex");
@@ -120,6 +120,39 @@ pub fn parse_shell_lc_plain_commands(command: &[String]) -> Option<Vec<Vec<Strin
     try_parse_word_only_commands_sequence(&tree, script)
 }
 
+/// Returns the program name of every command in `script`, including commands
+/// inside pipelines, subshells, control flow and substitutions. Returns `None`
+/// when the script does not parse cleanly or a command name is not a literal
+/// word (for example `$cmd`, `"curl"` or `cu\rl`), since such names are only
+/// known once the shell expands them.
+pub fn try_parse_command_names(script: &str) -> Option<Vec<String>> {
+    let tree = try_parse_shell(script)?;
+    let root = tree.root_node();
+    if root.has_error() {
+        return None;
+    }
+
+    let mut names = Vec::new();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if node.kind() == "command" {
+            let word = node.child_by_field_name("name")?.named_child(0)?;
+            if word.kind() != "word" {
+                return None;
+            }
+            let name = word.utf8_text(script.as_bytes()).ok()?;
+            if name != "[" && name.contains(['\\', '\'', '"', '$', '`', '*', '?', '[', '{']) {
+                return None;
+            }
+            names.push(name.to_owned());
+        }
+        let mut cursor = node.walk();
+        let children: Vec<Node> = node.named_children(&mut cursor).collect();
+        stack.extend(children.into_iter().rev());
+    }
+    Some(names)
+}
+
 fn parse_plain_command_from_node(cmd: tree_sitter::Node, src: &str) -> Option<Vec<String>> {
     if cmd.kind() != "command" {
         return None;
@@ -258,4 +291,21 @@ mod tests {
         let parsed = parse_shell_lc_plain_commands(&command).unwrap();
         assert_eq!(parsed, vec![vec!["ls".to_string()]]);
     }
+
+    #[test]
+    fn collects_command_names_from_compound_scripts() {
+        let names = try_parse_command_names(
+            "FOO=1 git status > out.txt && (cd src; rg foo | head) || echo $(curl -s x)",
+        )
+        .unwrap();
+        assert_eq!(names, vec!["git", "cd", "rg", "head", "echo", "curl"]);
+    }
+
+    #[test]
+    fn command_names_reject_non_literal_programs() {
+        assert!(try_parse_command_names("$CMD x").is_none());
+        assert!(try_parse_command_names("\"curl\" x").is_none());
+        assert!(try_parse_command_names("cu\\rl x").is_none());
+        assert!(try_parse_command_names("ls &&").is_none());
+    }
 }
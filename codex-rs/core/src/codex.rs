@@ -58,12 +58,14 @@ use crate::client_common::Prompt;
 use crate::client_common::ResponseEvent;
 use crate::config::Config;
 use crate::config::types::McpServerTransportConfig;
+use crate::config::types::ProgramPolicy;
 use crate::config::types::ShellEnvironmentPolicy;
 use crate::context_manager::ContextManager;
 use crate::environment_context::EnvironmentContext;
 use crate::error::CodexErr;
 use crate::error::Result as CodexResult;
 #[cfg(test)]
+use crate::exec::ExecControl;
 use crate::exec::StreamOutput;
 // Removed: legacy executor wiring replaced by ToolOrchestrator flows.
 // legacy normalize_exec_result no longer used after orchestrator migration
@@ -273,6 +275,10 @@ pub(crate) struct TurnContext {
     pub(crate) approval_policy: AskForApproval,
     pub(crate) sandbox_policy: SandboxPolicy,
     pub(crate) shell_environment_policy: ShellEnvironmentPolicy,
+    /// Timeout for shell tool calls that do not specify one; see
+    /// [`Config::default_exec_timeout_ms`].
+    pub(crate) default_exec_timeout_ms: Option<u64>,
+    pub(crate) program_policy: ProgramPolicy,
     pub(crate) tools_config: ToolsConfig,
     pub(crate) final_output_json_schema: Option<Value>,
     pub(crate) codex_linux_sandbox_exe: Option<PathBuf>,
@@ -429,6 +435,8 @@ impl Session {
             approval_policy: session_configuration.approval_policy,
             sandbox_policy: session_configuration.sandbox_policy.clone(),
             shell_environment_policy: config.shell_environment_policy.clone(),
+            default_exec_timeout_ms: config.default_exec_timeout_ms,
+            program_policy: config.program_policy.clone(),
             tools_config,
             final_output_json_schema: None,
             codex_linux_sandbox_exe: config.codex_linux_sandbox_exe.clone(),
@@ -927,6 +935,48 @@ impl Session {
         }
     }
 
+    /// Registers a pause/resume channel for the shell command `call_id` in
+    /// the active turn. Returns `None` when no turn is running.
+    pub(crate) async fn register_exec_control(
+        &self,
+        call_id: &str,
+    ) -> Option<async_channel::Receiver<ExecControl>> {
+        let active = self.active_turn.lock().await;
+        let at = active.as_ref()?;
+        let (tx, rx) = async_channel::unbounded();
+        at.turn_state
+            .lock()
+            .await
+            .insert_exec_control(call_id.to_string(), tx);
+        Some(rx)
+    }
+
+    pub(crate) async fn unregister_exec_control(&self, call_id: &str) {
+        let active = self.active_turn.lock().await;
+        if let Some(at) = active.as_ref() {
+            at.turn_state.lock().await.remove_exec_control(call_id);
+        }
+    }
+
+    /// Forwards `request` to the running shell command `call_id`.
+    pub async fn notify_exec_control(&self, call_id: &str, request: ExecControl) {
+        let tx = {
+            let active = self.active_turn.lock().await;
+            match active.as_ref() {
+                Some(at) => at.turn_state.lock().await.exec_control(call_id),
+                None => None,
+            }
+        };
+        match tx {
+            Some(tx) => {
+                tx.send(request).await.ok();
+            }
+            None => {
+                warn!("No running command found for call_id: {call_id}");
+            }
+        }
+    }
+
     /// Records input items: always append to conversation history and
     /// persist these response items to rollout.
     pub(crate) async fn record_conversation_items(
@@ -1297,6 +1347,12 @@ async fn submission_loop(sess: Arc<Session>, config: Arc<Config>, rx_sub: Receiv
             Op::PatchApproval { id, decision } => {
                 handlers::patch_approval(&sess, id, decision).await;
             }
+            Op::PauseExecCommand { call_id } => {
+                handlers::exec_control(&sess, call_id, ExecControl::Pause).await;
+            }
+            Op::ResumeExecCommand { call_id } => {
+                handlers::exec_control(&sess, call_id, ExecControl::Resume).await;
+            }
             Op::AddToHistory { text } => {
                 handlers::add_to_history(&sess, &config, text).await;
             }
@@ -1347,6 +1403,7 @@ mod handlers {
 
     use crate::codex::spawn_review_thread;
     use crate::config::Config;
+    use crate::exec::ExecControl;
     use crate::mcp::auth::compute_auth_statuses;
     use crate::tasks::CompactTask;
     use crate::tasks::RegularTask;
@@ -1454,6 +1511,10 @@ mod handlers {
         }
     }
 
+    pub async fn exec_control(sess: &Arc<Session>, call_id: String, request: ExecControl) {
+        sess.notify_exec_control(&call_id, request).await;
+    }
+
     pub async fn patch_approval(sess: &Arc<Session>, id: String, decision: ReviewDecision) {
         match decision {
             ReviewDecision::Abort => {
@@ -1699,6 +1760,8 @@ async fn spawn_review_thread(
         approval_policy: parent_turn_context.approval_policy,
         sandbox_policy: parent_turn_context.sandbox_policy.clone(),
         shell_environment_policy: parent_turn_context.shell_environment_policy.clone(),
+        default_exec_timeout_ms: parent_turn_context.default_exec_timeout_ms,
+        program_policy: parent_turn_context.program_policy.clone(),
         cwd: parent_turn_context.cwd.clone(),
         final_output_json_schema: None,
         codex_linux_sandbox_exe: parent_turn_context.codex_linux_sandbox_exe.clone(),
@@ -2419,6 +2482,9 @@ mod tests {
             aggregated_output: StreamOutput::new("Command output".to_string()),
             duration: StdDuration::from_secs(1),
             timed_out: true,
+            peak_rss_bytes: None,
+            output_lines: Vec::new(),
+            stopped_early: false,
         };
 
         let out = format_exec_output_str(&exec);
@@ -2710,6 +2776,32 @@ mod tests {
         assert!(rx.try_recv().is_err());
     }
 
+    #[tokio::test]
+    async fn exec_control_reaches_registered_command_only() {
+        let (session, _turn_context) = make_session_and_context();
+        assert!(session.register_exec_control("call-1").await.is_none());
+
+        *session.active_turn.lock().await = Some(ActiveTurn::default());
+        let rx = session
+            .register_exec_control("call-1")
+            .await
+            .expect("active turn accepts a control channel");
+        session
+            .notify_exec_control("call-1", ExecControl::Pause)
+            .await;
+        session
+            .notify_exec_control("call-2", ExecControl::Resume)
+            .await;
+        assert_eq!(rx.try_recv(), Ok(ExecControl::Pause));
+        assert!(rx.try_recv().is_err());
+
+        session.unregister_exec_control("call-1").await;
+        session
+            .notify_exec_control("call-1", ExecControl::Resume)
+            .await;
+        assert!(rx.try_recv().is_err());
+    }
+
     #[tokio::test]
     async fn abort_gracefuly_emits_turn_aborted_only() {
         let (sess, tc, rx) = make_session_and_context_with_rx();
@@ -2970,6 +3062,15 @@ mod tests {
             with_escalated_permissions: Some(true),
             justification: Some("test".to_string()),
             arg0: None,
+            require_cwd_in_writable_roots: false,
+            allocate_pty: false,
+            capture_output_lines: false,
+            stop_on_first_stdout_byte: false,
+            stdin: None,
+            merge_stderr_into_stdout: false,
+            umask: None,
+            program_policy: Default::default(),
+            env_policy: Default::default(),
         };
 
         let params2 = ExecParams {
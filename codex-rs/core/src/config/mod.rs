@@ -1,12 +1,15 @@
 use crate::auth::AuthCredentialsStoreMode;
 use crate::config::types::DEFAULT_OTEL_ENVIRONMENT;
 use crate::config::types::History;
+use crate::config::types::LimitGlyphs;
 use crate::config::types::McpServerConfig;
+use crate::config::types::ModelPricing;
 use crate::config::types::Notice;
 use crate::config::types::Notifications;
 use crate::config::types::OtelConfig;
 use crate::config::types::OtelConfigToml;
 use crate::config::types::OtelExporterKind;
+use crate::config::types::ProgramPolicy;
 use crate::config::types::ReasoningSummaryFormat;
 use crate::config::types::SandboxWorkspaceWrite;
 use crate::config::types::ShellEnvironmentPolicy;
@@ -32,6 +35,7 @@ use crate::project_doc::DEFAULT_PROJECT_DOC_FILENAME;
 use crate::project_doc::LOCAL_PROJECT_DOC_FILENAME;
 use crate::protocol::AskForApproval;
 use crate::protocol::SandboxPolicy;
+use crate::protocol::SandboxPolicyBuilder;
 use codex_app_server_protocol::Tools;
 use codex_app_server_protocol::UserSavedConfig;
 use codex_protocol::config_types::ForcedLoginMethod;
@@ -113,6 +117,14 @@ pub struct Config {
 
     pub shell_environment_policy: ShellEnvironmentPolicy,
 
+    /// Programs that commands run on the model's behalf may not spawn.
+    pub program_policy: ProgramPolicy,
+
+    /// Timeout applied to shell tool calls that do not specify one. `None`
+    /// falls back to the exec layer's built-in default; `Some(0)` disables
+    /// the timeout.
+    pub default_exec_timeout_ms: Option<u64>,
+
     /// When `true`, `AgentReasoning` events emitted by the backend will be
     /// suppressed from the frontend output. This can reduce visual noise when
     /// users are only interested in the final agent responses.
@@ -160,6 +172,29 @@ pub struct Config {
     /// and turn completions when not focused.
     pub tui_notifications: Notifications,
 
+    /// Text shown for `Agents.md` in the TUI `/status` card when no project
+    /// docs are found. `None` uses the default `<none>`.
+    pub tui_agents_md_empty_message: Option<String>,
+
+    /// When `true`, `@` file search in the TUI also returns files that are
+    /// excluded by `.gitignore`, `.ignore`, or `.git/info/exclude`.
+    pub tui_file_search_include_ignored: bool,
+
+    /// Whether `@` file search in the TUI collapses matches that differ only
+    /// in case. `None` uses the platform default.
+    pub tui_file_search_case_fold: Option<bool>,
+
+    /// Lower bound on the `@` file search debounce in the TUI. `None` leaves
+    /// the adaptive debounce alone.
+    pub tui_file_search_min_debounce_ms: Option<u64>,
+
+    /// Per-model token prices for the `/status` cost estimate, keyed by model
+    /// slug.
+    pub tui_model_pricing: HashMap<String, ModelPricing>,
+
+    /// Glyphs prefixed to rate-limit window labels in `/status`.
+    pub tui_limit_glyphs: LimitGlyphs,
+
     /// The directory that should be treated as the current working directory
     /// for the session. All relative paths inside the business-logic layer are
     /// resolved against this path.
@@ -529,6 +564,17 @@ pub struct ConfigToml {
     #[serde(default)]
     pub shell_environment_policy: ShellEnvironmentPolicyToml,
 
+    /// Program basenames (e.g. `curl`) that commands may not spawn.
+    pub denied_programs: Option<Vec<String>>,
+
+    /// When set, the only program basenames commands may spawn.
+    /// `denied_programs` still wins for programs listed in both.
+    pub allowed_programs: Option<Vec<String>>,
+
+    /// Timeout in milliseconds for shell tool calls that do not specify one.
+    /// Set to `0` to let such calls run without a timeout.
+    pub default_exec_timeout_ms: Option<u64>,
+
     /// Sandbox mode to use.
     pub sandbox_mode: Option<SandboxMode>,
 
@@ -724,13 +770,15 @@ pub struct SandboxPolicyResolution {
 }
 
 impl ConfigToml {
-    /// Derive the effective sandbox policy from the configuration.
+    /// Derive the effective sandbox policy from the configuration. Relative
+    /// `sandbox_workspace_write.writable_roots` are resolved against
+    /// `resolved_cwd`; ones that climb out of it are rejected.
     fn derive_sandbox_policy(
         &self,
         sandbox_mode_override: Option<SandboxMode>,
         profile_sandbox_mode: Option<SandboxMode>,
         resolved_cwd: &Path,
-    ) -> SandboxPolicyResolution {
+    ) -> std::io::Result<SandboxPolicyResolution> {
         let resolved_sandbox_mode = sandbox_mode_override
             .or(profile_sandbox_mode)
             .or(self.sandbox_mode)
@@ -753,12 +801,20 @@ impl ConfigToml {
                     network_access,
                     exclude_tmpdir_env_var,
                     exclude_slash_tmp,
-                }) => SandboxPolicy::WorkspaceWrite {
-                    writable_roots: writable_roots.clone(),
-                    network_access: *network_access,
-                    exclude_tmpdir_env_var: *exclude_tmpdir_env_var,
-                    exclude_slash_tmp: *exclude_slash_tmp,
-                },
+                }) => {
+                    let mut builder = SandboxPolicyBuilder::new()
+                        .exclude_tmpdir_env_var(*exclude_tmpdir_env_var)
+                        .exclude_slash_tmp(*exclude_slash_tmp);
+                    if *network_access {
+                        builder = builder.allow_network();
+                    }
+                    for root in writable_roots {
+                        builder = builder.writable_root(root.clone());
+                    }
+                    builder
+                        .build_with_cwd(resolved_cwd)
+                        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?
+                }
                 None => SandboxPolicy::new_workspace_write_policy(),
             },
             SandboxMode::DangerFullAccess => SandboxPolicy::DangerFullAccess,
@@ -772,10 +828,10 @@ impl ConfigToml {
             sandbox_policy = SandboxPolicy::new_read_only_policy();
             forced_auto_mode_downgraded_on_windows = true;
         }
-        SandboxPolicyResolution {
+        Ok(SandboxPolicyResolution {
             policy: sandbox_policy,
             forced_auto_mode_downgraded_on_windows,
-        }
+        })
     }
 
     /// Resolves the cwd to an existing project, or returns None if ConfigToml
@@ -943,7 +999,7 @@ impl Config {
         let SandboxPolicyResolution {
             policy: mut sandbox_policy,
             forced_auto_mode_downgraded_on_windows,
-        } = cfg.derive_sandbox_policy(sandbox_mode, config_profile.sandbox_mode, &resolved_cwd);
+        } = cfg.derive_sandbox_policy(sandbox_mode, config_profile.sandbox_mode, &resolved_cwd)?;
         if let SandboxPolicy::WorkspaceWrite { writable_roots, .. } = &mut sandbox_policy {
             for path in additional_writable_roots {
                 if !writable_roots.iter().any(|existing| existing == &path) {
@@ -1098,6 +1154,11 @@ impl Config {
             did_user_set_custom_approval_policy_or_sandbox_mode,
             forced_auto_mode_downgraded_on_windows,
             shell_environment_policy,
+            program_policy: ProgramPolicy {
+                denied_programs: cfg.denied_programs.unwrap_or_default(),
+                allowed_programs: cfg.allowed_programs,
+            },
+            default_exec_timeout_ms: cfg.default_exec_timeout_ms,
             notify: cfg.notify,
             user_instructions,
             base_instructions,
@@ -1165,6 +1226,25 @@ impl Config {
                 .as_ref()
                 .map(|t| t.notifications.clone())
                 .unwrap_or_default(),
+            tui_agents_md_empty_message: cfg
+                .tui
+                .as_ref()
+                .and_then(|t| t.agents_md_empty_message.clone()),
+            tui_file_search_include_ignored: cfg
+                .tui
+                .as_ref()
+                .is_some_and(|t| t.file_search_include_ignored),
+            tui_file_search_case_fold: cfg.tui.as_ref().and_then(|t| t.file_search_case_fold),
+            tui_file_search_min_debounce_ms: cfg
+                .tui
+                .as_ref()
+                .and_then(|t| t.file_search_min_debounce_ms),
+            tui_model_pricing: cfg
+                .tui
+                .as_ref()
+                .map(|t| t.model_pricing.clone())
+                .unwrap_or_default(),
+            tui_limit_glyphs: cfg.tui.as_ref().map(|t| t.limit_glyphs).unwrap_or_default(),
             otel: {
                 let t: OtelConfigToml = cfg.otel.unwrap_or_default();
                 let log_user_prompt = t.log_user_prompt.unwrap_or(false);
@@ -1346,11 +1426,9 @@ network_access = false  # This should be ignored.
         let sandbox_full_access_cfg = toml::from_str::<ConfigToml>(sandbox_full_access)
             .expect("TOML deserialization should succeed");
         let sandbox_mode_override = None;
-        let resolution = sandbox_full_access_cfg.derive_sandbox_policy(
-            sandbox_mode_override,
-            None,
-            &PathBuf::from("/tmp/test"),
-        );
+        let resolution = sandbox_full_access_cfg
+            .derive_sandbox_policy(sandbox_mode_override, None, &PathBuf::from("/tmp/test"))
+            .expect("sandbox policy");
         assert_eq!(
             resolution,
             SandboxPolicyResolution {
@@ -1369,11 +1447,9 @@ network_access = true  # This should be ignored.
         let sandbox_read_only_cfg = toml::from_str::<ConfigToml>(sandbox_read_only)
             .expect("TOML deserialization should succeed");
         let sandbox_mode_override = None;
-        let resolution = sandbox_read_only_cfg.derive_sandbox_policy(
-            sandbox_mode_override,
-            None,
-            &PathBuf::from("/tmp/test"),
-        );
+        let resolution = sandbox_read_only_cfg
+            .derive_sandbox_policy(sandbox_mode_override, None, &PathBuf::from("/tmp/test"))
+            .expect("sandbox policy");
         assert_eq!(
             resolution,
             SandboxPolicyResolution {
@@ -1396,11 +1472,9 @@ exclude_slash_tmp = true
         let sandbox_workspace_write_cfg = toml::from_str::<ConfigToml>(sandbox_workspace_write)
             .expect("TOML deserialization should succeed");
         let sandbox_mode_override = None;
-        let resolution = sandbox_workspace_write_cfg.derive_sandbox_policy(
-            sandbox_mode_override,
-            None,
-            &PathBuf::from("/tmp/test"),
-        );
+        let resolution = sandbox_workspace_write_cfg
+            .derive_sandbox_policy(sandbox_mode_override, None, &PathBuf::from("/tmp/test"))
+            .expect("sandbox policy");
         if cfg!(target_os = "windows") {
             assert_eq!(
                 resolution,
@@ -1441,11 +1515,9 @@ trust_level = "trusted"
         let sandbox_workspace_write_cfg = toml::from_str::<ConfigToml>(sandbox_workspace_write)
             .expect("TOML deserialization should succeed");
         let sandbox_mode_override = None;
-        let resolution = sandbox_workspace_write_cfg.derive_sandbox_policy(
-            sandbox_mode_override,
-            None,
-            &PathBuf::from("/tmp/test"),
-        );
+        let resolution = sandbox_workspace_write_cfg
+            .derive_sandbox_policy(sandbox_mode_override, None, &PathBuf::from("/tmp/test"))
+            .expect("sandbox policy");
         if cfg!(target_os = "windows") {
             assert_eq!(
                 resolution,
@@ -1470,6 +1542,62 @@ trust_level = "trusted"
         }
     }
 
+    #[test]
+    fn relative_workspace_writable_roots_resolve_against_cwd() {
+        let cfg = toml::from_str::<ConfigToml>(
+            r#"
+sandbox_mode = "workspace-write"
+
+[sandbox_workspace_write]
+writable_roots = ["build/out", "/my/workspace"]
+"#,
+        )
+        .expect("TOML deserialization should succeed");
+
+        let resolution = cfg
+            .derive_sandbox_policy(None, None, &PathBuf::from("/tmp/test"))
+            .expect("sandbox policy");
+
+        if !cfg!(target_os = "windows") {
+            assert_eq!(
+                resolution.policy,
+                SandboxPolicy::WorkspaceWrite {
+                    writable_roots: vec![
+                        PathBuf::from("/tmp/test/build/out"),
+                        PathBuf::from("/my/workspace"),
+                    ],
+                    network_access: false,
+                    exclude_tmpdir_env_var: false,
+                    exclude_slash_tmp: false,
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn escaping_workspace_writable_root_is_a_config_error() {
+        let cfg = toml::from_str::<ConfigToml>(
+            r#"
+sandbox_mode = "workspace-write"
+
+[sandbox_workspace_write]
+writable_roots = ["../outside"]
+"#,
+        )
+        .expect("TOML deserialization should succeed");
+
+        let err = cfg
+            .derive_sandbox_policy(None, None, &PathBuf::from("/tmp/test"))
+            .expect_err("escaping root should be rejected");
+
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert!(
+            err.to_string()
+                .ends_with("which is outside the working directory"),
+            "unexpected error: {err}"
+        );
+    }
+
     #[test]
     fn add_dir_override_extends_workspace_writable_roots() -> std::io::Result<()> {
         let temp_dir = TempDir::new()?;
@@ -1581,6 +1709,25 @@ trust_level = "trusted"
         Ok(())
     }
 
+    #[test]
+    fn default_exec_timeout_ms_reads_from_toml() -> std::io::Result<()> {
+        let codex_home = TempDir::new()?;
+        let cfg = ConfigToml {
+            default_exec_timeout_ms: Some(0),
+            ..Default::default()
+        };
+
+        let config = Config::load_from_base_config_with_overrides(
+            cfg,
+            ConfigOverrides::default(),
+            codex_home.path().to_path_buf(),
+        )?;
+
+        assert_eq!(config.default_exec_timeout_ms, Some(0));
+
+        Ok(())
+    }
+
     #[test]
     fn profile_legacy_toggles_override_base() -> std::io::Result<()> {
         let codex_home = TempDir::new()?;
@@ -2868,6 +3015,8 @@ model_verbosity = "high"
                 did_user_set_custom_approval_policy_or_sandbox_mode: true,
                 forced_auto_mode_downgraded_on_windows: false,
                 shell_environment_policy: ShellEnvironmentPolicy::default(),
+                program_policy: ProgramPolicy::default(),
+                default_exec_timeout_ms: None,
                 user_instructions: None,
                 notify: None,
                 cwd: fixture.cwd(),
@@ -2904,6 +3053,12 @@ model_verbosity = "high"
                 notices: Default::default(),
                 disable_paste_burst: false,
                 tui_notifications: Default::default(),
+                tui_agents_md_empty_message: None,
+                tui_file_search_include_ignored: false,
+                tui_file_search_case_fold: None,
+                tui_file_search_min_debounce_ms: None,
+                tui_model_pricing: HashMap::new(),
+                tui_limit_glyphs: LimitGlyphs::None,
                 otel: OtelConfig::default(),
             },
             o3_profile_config
@@ -2939,6 +3094,8 @@ model_verbosity = "high"
             did_user_set_custom_approval_policy_or_sandbox_mode: true,
             forced_auto_mode_downgraded_on_windows: false,
             shell_environment_policy: ShellEnvironmentPolicy::default(),
+            program_policy: ProgramPolicy::default(),
+            default_exec_timeout_ms: None,
             user_instructions: None,
             notify: None,
             cwd: fixture.cwd(),
@@ -2975,6 +3132,12 @@ model_verbosity = "high"
             notices: Default::default(),
             disable_paste_burst: false,
             tui_notifications: Default::default(),
+            tui_agents_md_empty_message: None,
+            tui_file_search_include_ignored: false,
+            tui_file_search_case_fold: None,
+            tui_file_search_min_debounce_ms: None,
+            tui_model_pricing: HashMap::new(),
+            tui_limit_glyphs: LimitGlyphs::None,
             otel: OtelConfig::default(),
         };
 
@@ -3025,6 +3188,8 @@ model_verbosity = "high"
             did_user_set_custom_approval_policy_or_sandbox_mode: true,
             forced_auto_mode_downgraded_on_windows: false,
             shell_environment_policy: ShellEnvironmentPolicy::default(),
+            program_policy: ProgramPolicy::default(),
+            default_exec_timeout_ms: None,
             user_instructions: None,
             notify: None,
             cwd: fixture.cwd(),
@@ -3061,6 +3226,12 @@ model_verbosity = "high"
             notices: Default::default(),
             disable_paste_burst: false,
             tui_notifications: Default::default(),
+            tui_agents_md_empty_message: None,
+            tui_file_search_include_ignored: false,
+            tui_file_search_case_fold: None,
+            tui_file_search_min_debounce_ms: None,
+            tui_model_pricing: HashMap::new(),
+            tui_limit_glyphs: LimitGlyphs::None,
             otel: OtelConfig::default(),
         };
 
@@ -3097,6 +3268,8 @@ model_verbosity = "high"
             did_user_set_custom_approval_policy_or_sandbox_mode: true,
             forced_auto_mode_downgraded_on_windows: false,
             shell_environment_policy: ShellEnvironmentPolicy::default(),
+            program_policy: ProgramPolicy::default(),
+            default_exec_timeout_ms: None,
             user_instructions: None,
             notify: None,
             cwd: fixture.cwd(),
@@ -3133,6 +3306,12 @@ model_verbosity = "high"
             notices: Default::default(),
             disable_paste_burst: false,
             tui_notifications: Default::default(),
+            tui_agents_md_empty_message: None,
+            tui_file_search_include_ignored: false,
+            tui_file_search_case_fold: None,
+            tui_file_search_min_debounce_ms: None,
+            tui_model_pricing: HashMap::new(),
+            tui_limit_glyphs: LimitGlyphs::None,
             otel: OtelConfig::default(),
         };
 
@@ -349,6 +349,58 @@ pub struct Tui {
     /// Defaults to `false`.
     #[serde(default)]
     pub notifications: Notifications,
+
+    /// Text shown for `Agents.md` in `/status` when no project docs are
+    /// found. Defaults to `<none>`.
+    #[serde(default)]
+    pub agents_md_empty_message: Option<String>,
+
+    /// Include files matched by `.gitignore` (and related ignore files) in
+    /// `@` file search results. Defaults to `false`.
+    #[serde(default)]
+    pub file_search_include_ignored: bool,
+
+    /// Treat `@` file search matches that differ only in case as duplicates.
+    /// Defaults to on for macOS and Windows, off elsewhere.
+    #[serde(default)]
+    pub file_search_case_fold: Option<bool>,
+
+    /// Minimum delay in milliseconds between typing in an `@` token and
+    /// starting a file search. Useful for very large repos. By default the
+    /// delay adapts to how long searches take.
+    #[serde(default)]
+    pub file_search_min_debounce_ms: Option<u64>,
+
+    /// Per-model token prices used to estimate session cost in `/status`,
+    /// keyed by model slug. Models without an entry show no estimate.
+    #[serde(default)]
+    pub model_pricing: HashMap<String, ModelPricing>,
+
+    /// Glyphs prefixed to rate-limit window labels in `/status`. Defaults to
+    /// `none`.
+    #[serde(default)]
+    pub limit_glyphs: LimitGlyphs,
+}
+
+/// Glyph set used to mark rate-limit windows: a clock for windows of a day or
+/// less and a calendar for weekly and longer windows.
+#[derive(Deserialize, Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum LimitGlyphs {
+    /// No glyphs.
+    #[default]
+    None,
+    /// Single-width Unicode symbols.
+    Unicode,
+    /// Plain ASCII for terminals without good symbol support.
+    Ascii,
+}
+
+/// Token prices for a model, in US dollars per million tokens.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct ModelPricing {
+    pub input_per_million_tokens: f64,
+    pub output_per_million_tokens: f64,
 }
 
 /// Settings for notices we display to users via the tui and app-server clients
@@ -424,6 +476,10 @@ pub struct ShellEnvironmentPolicyToml {
     pub include_only: Option<Vec<String>>,
 
     pub experimental_use_profile: Option<bool>,
+
+    /// Additional patterns whose values are masked when the environment is
+    /// logged.
+    pub redact: Option<Vec<String>>,
 }
 
 pub type EnvironmentVariablePattern = WildMatchPattern<'*', '?'>;
@@ -435,6 +491,10 @@ pub type EnvironmentVariablePattern = WildMatchPattern<'*', '?'>;
 /// 3. If `exclude` is not empty, filter the map using the provided patterns.
 /// 4. Insert any entries from `r#set` into the map.
 /// 5. If non-empty, filter the map using the `include_only` patterns.
+///
+/// When the resulting `env` is rendered for display (e.g., in logs), values for
+/// names matching `"*KEY*"`, `"*TOKEN*"`, `"*SECRET*"`, `"*PASSWORD*"`, or any
+/// of the `redact` patterns are masked.
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct ShellEnvironmentPolicy {
     /// Starting point when building the environment.
@@ -455,6 +515,10 @@ pub struct ShellEnvironmentPolicy {
 
     /// If true, the shell profile will be used to run the command.
     pub use_profile: bool,
+
+    /// Environment variable names whose values are masked when displayed, in
+    /// addition to the default sensitive patterns.
+    pub redact: Vec<EnvironmentVariablePattern>,
 }
 
 impl From<ShellEnvironmentPolicyToml> for ShellEnvironmentPolicy {
@@ -476,6 +540,12 @@ impl From<ShellEnvironmentPolicyToml> for ShellEnvironmentPolicy {
             .map(|s| EnvironmentVariablePattern::new_case_insensitive(&s))
             .collect();
         let use_profile = toml.experimental_use_profile.unwrap_or(false);
+        let redact = toml
+            .redact
+            .unwrap_or_default()
+            .into_iter()
+            .map(|s| EnvironmentVariablePattern::new_case_insensitive(&s))
+            .collect();
 
         Self {
             inherit,
@@ -484,10 +554,23 @@ impl From<ShellEnvironmentPolicyToml> for ShellEnvironmentPolicy {
             r#set,
             include_only,
             use_profile,
+            redact,
         }
     }
 }
 
+/// Programs the exec layer refuses to spawn regardless of the sandbox policy.
+/// Entries are compared against the basename of the command's program.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ProgramPolicy {
+    /// Program basenames that are never spawned, e.g. `curl` or `nc`. Takes
+    /// precedence over `allowed_programs`.
+    pub denied_programs: Vec<String>,
+    /// When `Some`, only these program basenames may be spawned. `None`
+    /// allows any program that is not denied.
+    pub allowed_programs: Option<Vec<String>>,
+}
+
 #[derive(Deserialize, Debug, Clone, PartialEq, Eq, Default, Hash)]
 #[serde(rename_all = "kebab-case")]
 pub enum ReasoningSummaryFormat {
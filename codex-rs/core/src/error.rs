@@ -13,6 +13,7 @@ use codex_protocol::protocol::RateLimitSnapshot;
 use reqwest::StatusCode;
 use serde_json;
 use std::io;
+use std::path::PathBuf;
 use std::time::Duration;
 use thiserror::Error;
 use tokio::task::JoinError;
@@ -49,6 +50,38 @@ pub enum SandboxErr {
     #[error("command was killed by a signal")]
     Signal(i32),
 
+    /// Command was cancelled by the caller before it finished
+    #[error("command was cancelled")]
+    Cancelled,
+
+    /// The command's working directory is not inside any writable root
+    #[error(
+        "working directory {} is outside the sandbox's writable roots",
+        .cwd.display()
+    )]
+    CwdOutsideWritableRoots { cwd: PathBuf },
+
+    /// The sandbox helper executable is not present at the expected path
+    #[error(
+        "sandbox helper {} is missing; reinstall Codex or point it at a valid codex-linux-sandbox binary",
+        .path.display()
+    )]
+    HelperMissing { path: PathBuf },
+
+    /// The command's program is blocked by the configured program policy
+    #[error("`{program}` is not allowed to run: it is listed in denied_programs")]
+    ProgramDenied { program: String },
+
+    /// The command's program is missing from the configured allowlist
+    #[error("`{program}` is not allowed to run: it is not listed in allowed_programs")]
+    ProgramNotAllowed { program: String },
+
+    /// A shell script could not be checked against the program policy
+    #[error(
+        "`{shell}` script could not be checked against the program policy; run the programs directly or use a simpler script"
+    )]
+    UncheckableScript { shell: String },
+
     /// Error from linux landlock
     #[error("Landlock was not able to fully enforce all sandbox rules")]
     LandlockRestrict,
@@ -91,6 +124,11 @@ pub enum CodexErr {
     #[error("spawn failed: child stdout/stderr not captured")]
     Spawn,
 
+    /// The command's executable could not be found when spawning it, as
+    /// opposed to a command that ran and exited with 127.
+    #[error("command not found: {program}")]
+    CommandNotFound { program: String },
+
     /// Returned by run_command_stream when the user pressed Ctrl‑C (SIGINT). Session uses this to
     /// surface a polite FunctionCallOutput back to the model instead of crashing the CLI.
     #[error("interrupted (Ctrl-C). Something went wrong? Hit `/feedback` to report the issue.")]
@@ -529,6 +567,9 @@ mod tests {
             aggregated_output: StreamOutput::new("aggregate detail".to_string()),
             duration: Duration::from_millis(10),
             timed_out: false,
+            peak_rss_bytes: None,
+            output_lines: Vec::new(),
+            stopped_early: false,
         };
         let err = CodexErr::Sandbox(SandboxErr::Denied {
             output: Box::new(output),
@@ -545,6 +586,9 @@ mod tests {
             aggregated_output: StreamOutput::new(String::new()),
             duration: Duration::from_millis(10),
             timed_out: false,
+            peak_rss_bytes: None,
+            output_lines: Vec::new(),
+            stopped_early: false,
         };
         let err = CodexErr::Sandbox(SandboxErr::Denied {
             output: Box::new(output),
@@ -561,6 +605,9 @@ mod tests {
             aggregated_output: StreamOutput::new(String::new()),
             duration: Duration::from_millis(8),
             timed_out: false,
+            peak_rss_bytes: None,
+            output_lines: Vec::new(),
+            stopped_early: false,
         };
         let err = CodexErr::Sandbox(SandboxErr::Denied {
             output: Box::new(output),
@@ -577,6 +624,9 @@ mod tests {
             aggregated_output: StreamOutput::new(String::new()),
             duration: Duration::from_millis(5),
             timed_out: false,
+            peak_rss_bytes: None,
+            output_lines: Vec::new(),
+            stopped_early: false,
         };
         let err = CodexErr::Sandbox(SandboxErr::Denied {
             output: Box::new(output),
@@ -6,23 +6,38 @@ use std::io;
 use std::path::Path;
 use std::path::PathBuf;
 use std::process::ExitStatus;
+use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
 
+use async_channel::Receiver;
 use async_channel::Sender;
 use tokio::io::AsyncRead;
 use tokio::io::AsyncReadExt;
+use tokio::io::AsyncWriteExt;
 use tokio::io::BufReader;
 use tokio::process::Child;
+use tokio::sync::Notify;
+#[cfg(unix)]
+use tokio::sync::broadcast::error::RecvError;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
 
+use crate::bash::try_parse_command_names;
+use crate::config::types::ProgramPolicy;
+use crate::config::types::ShellEnvironmentPolicy;
 use crate::error::CodexErr;
 use crate::error::Result;
 use crate::error::SandboxErr;
+use crate::exec_env::redact_env;
 use crate::protocol::Event;
 use crate::protocol::EventMsg;
 use crate::protocol::ExecCommandOutputDeltaEvent;
+use crate::protocol::ExecCommandPausedEvent;
+use crate::protocol::ExecCommandResumedEvent;
 use crate::protocol::ExecOutputStream;
 use crate::protocol::SandboxPolicy;
+use crate::protocol::normalize_lexically;
 use crate::sandboxing::CommandSpec;
 use crate::sandboxing::ExecEnv;
 use crate::sandboxing::SandboxManager;
@@ -41,6 +56,8 @@ const EXEC_TIMEOUT_EXIT_CODE: i32 = 124; // conventional timeout exit code
 // I/O buffer sizing
 const READ_CHUNK_SIZE: usize = 8192; // bytes per read
 const AGGREGATE_BUFFER_INITIAL_CAPACITY: usize = 8 * 1024; // 8 KiB
+#[cfg(unix)]
+const PTY_OUTPUT_DRAIN_TIMEOUT: Duration = Duration::from_millis(100);
 
 /// Limit the number of ExecCommandOutputDelta events emitted per exec call.
 /// Aggregation still collects full output; only the live event stream is capped.
@@ -55,11 +72,53 @@ pub struct ExecParams {
     pub with_escalated_permissions: Option<bool>,
     pub justification: Option<String>,
     pub arg0: Option<String>,
+    /// When true and the sandbox policy is `WorkspaceWrite`, refuse to spawn
+    /// the command unless `cwd` is inside one of the writable roots.
+    pub require_cwd_in_writable_roots: bool,
+    /// When true, run the command attached to a pseudo-terminal and capture
+    /// its combined output as stdout. Only supported on Unix.
+    pub allocate_pty: bool,
+    /// When true, also record each output line with its stream and arrival
+    /// time so the result exposes a merged chronological view.
+    pub capture_output_lines: bool,
+    /// When true, kill the command's process group as soon as it writes to
+    /// stdout and report success with `ExecToolCallOutput::stopped_early`.
+    /// Useful for existence checks. Ignored with `allocate_pty`.
+    pub stop_on_first_stdout_byte: bool,
+    /// Bytes written to the command's stdin, which is then closed. `None`
+    /// leaves stdin at `/dev/null`. Ignored with `allocate_pty`.
+    pub stdin: Option<Vec<u8>>,
+    /// When true, the command's stderr is written into its stdout pipe so
+    /// both streams keep their relative order; `stderr` is returned empty.
+    /// Only supported on Unix and ignored with `allocate_pty`.
+    pub merge_stderr_into_stdout: bool,
+    /// File mode creation mask applied in the child before exec, e.g. `0o077`
+    /// to keep created files private. `None` inherits Codex's umask. Unix
+    /// only; ignored with `allocate_pty`.
+    pub umask: Option<u32>,
+    /// Programs that may not be spawned; checked before the sandbox is set up.
+    pub program_policy: ProgramPolicy,
+    /// Policy `env` was built from; its `redact` patterns mask values when
+    /// the spawn is traced.
+    pub env_policy: ShellEnvironmentPolicy,
 }
 
 impl ExecParams {
     pub fn timeout_duration(&self) -> Duration {
-        Duration::from_millis(self.timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS))
+        timeout_duration(self.timeout_ms)
+    }
+}
+
+/// Resolve a per-call timeout: `None` uses [`DEFAULT_TIMEOUT_MS`] and
+/// `Some(0)` means no timeout. Callers that want a configurable default
+/// (see `Config::default_exec_timeout_ms`) fill in `timeout_ms` before it
+/// reaches the exec layer.
+fn timeout_duration(timeout_ms: Option<u64>) -> Duration {
+    match timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS) {
+        // `tokio::time::sleep` clamps deadlines that overflow `Instant`, so
+        // this effectively never fires.
+        0 => Duration::MAX,
+        ms => Duration::from_millis(ms),
     }
 }
 
@@ -82,6 +141,21 @@ pub struct StdoutStream {
     pub sub_id: String,
     pub call_id: String,
     pub tx_event: Sender<Event>,
+    /// Optional channel for pausing/resuming the running command. Each
+    /// accepted request is reported back on `tx_event`.
+    pub control_rx: Option<Receiver<ExecControl>>,
+}
+
+/// Requests that can be sent to a running command through
+/// [`StdoutStream::control_rx`].
+///
+/// Pausing stops the child's whole process group (SIGSTOP) and resuming
+/// continues it (SIGCONT). The timeout keeps running while paused. Only
+/// supported on Unix; elsewhere requests are ignored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExecControl {
+    Pause,
+    Resume,
 }
 
 pub async fn process_exec_tool_call(
@@ -91,6 +165,7 @@ pub async fn process_exec_tool_call(
     sandbox_cwd: &Path,
     codex_linux_sandbox_exe: &Option<PathBuf>,
     stdout_stream: Option<StdoutStream>,
+    cancellation_token: Option<CancellationToken>,
 ) -> Result<ExecToolCallOutput> {
     let ExecParams {
         command,
@@ -100,14 +175,30 @@ pub async fn process_exec_tool_call(
         with_escalated_permissions,
         justification,
         arg0: _,
+        require_cwd_in_writable_roots,
+        allocate_pty,
+        capture_output_lines,
+        stop_on_first_stdout_byte,
+        stdin,
+        merge_stderr_into_stdout,
+        umask,
+        program_policy,
+        env_policy,
     } = params;
 
+    if require_cwd_in_writable_roots {
+        ensure_cwd_in_writable_roots(&cwd, sandbox_policy, sandbox_cwd)?;
+    }
+
+    ensure_program_allowed(&command, &program_policy)?;
+
     let (program, args) = command.split_first().ok_or_else(|| {
         CodexErr::Io(io::Error::new(
             io::ErrorKind::InvalidInput,
             "command args are empty",
         ))
     })?;
+    ensure_sandboxed_program_exists(program, &env, &cwd, sandbox_type)?;
 
     let spec = CommandSpec {
         program: program.clone(),
@@ -117,6 +208,12 @@ pub async fn process_exec_tool_call(
         timeout_ms,
         with_escalated_permissions,
         justification,
+        capture_output_lines,
+        stop_on_first_stdout_byte,
+        stdin,
+        merge_stderr_into_stdout,
+        umask,
+        env_policy,
     };
 
     let manager = SandboxManager::new();
@@ -130,14 +227,289 @@ pub async fn process_exec_tool_call(
         )
         .map_err(CodexErr::from)?;
 
+    if allocate_pty {
+        let sandbox = exec_env.sandbox;
+        let start = Instant::now();
+        let raw_output_result = exec_in_pty(exec_env, cancellation_token).await;
+        return finalize_exec_result(raw_output_result, sandbox, start.elapsed());
+    }
+
     // Route through the sandboxing module for a single, unified execution path.
-    crate::sandboxing::execute_env(&exec_env, sandbox_policy, stdout_stream).await
+    crate::sandboxing::execute_env(&exec_env, sandbox_policy, stdout_stream, cancellation_token)
+        .await
+}
+
+/// Under Seatbelt or Landlock the spawned process is the sandbox wrapper, so a
+/// missing program would only show up as the wrapper's exit status (127 or a
+/// panic). Resolve it up front against the command's own `PATH` and report
+/// [`CodexErr::CommandNotFound`] instead. Unsandboxed spawns detect this from
+/// the spawn error in [`exec`].
+fn ensure_sandboxed_program_exists(
+    program: &str,
+    env: &HashMap<String, String>,
+    cwd: &Path,
+    sandbox_type: SandboxType,
+) -> Result<()> {
+    if !matches!(
+        sandbox_type,
+        SandboxType::MacosSeatbelt | SandboxType::LinuxSeccomp
+    ) {
+        return Ok(());
+    }
+    // Matches the search path `execvp` falls back to when PATH is unset.
+    let path = env
+        .get("PATH")
+        .map(String::as_str)
+        .unwrap_or("/bin:/usr/bin");
+    if which::which_in(program, Some(path), cwd).is_err() {
+        return Err(CodexErr::CommandNotFound {
+            program: program.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Fails with [`SandboxErr::CwdOutsideWritableRoots`] when `sandbox_policy` is
+/// `WorkspaceWrite` and `cwd` (resolved against `sandbox_cwd`) does not lie
+/// within any of its writable roots. Both sides are normalized lexically first
+/// so `..` components cannot step out of a root. Other policies always pass.
+fn ensure_cwd_in_writable_roots(
+    cwd: &Path,
+    sandbox_policy: &SandboxPolicy,
+    sandbox_cwd: &Path,
+) -> Result<()> {
+    if !matches!(sandbox_policy, SandboxPolicy::WorkspaceWrite { .. }) {
+        return Ok(());
+    }
+    let cwd = normalize_lexically(&sandbox_cwd.join(cwd));
+    let in_writable_root = sandbox_policy
+        .get_writable_roots_with_cwd(sandbox_cwd)
+        .iter()
+        .any(|writable_root| cwd.starts_with(normalize_lexically(&writable_root.root)));
+    if in_writable_root {
+        Ok(())
+    } else {
+        Err(CodexErr::Sandbox(SandboxErr::CwdOutsideWritableRoots {
+            cwd,
+        }))
+    }
+}
+
+/// Fails with [`SandboxErr::ProgramDenied`] when the basename of `command`'s
+/// program is denied by `program_policy`, or with
+/// [`SandboxErr::ProgramNotAllowed`] when an allowlist is set and does not
+/// include it. The denylist is checked first.
+///
+/// For `bash`/`zsh`/`sh` invoked with `-c` or `-lc`, every command in the
+/// script is checked as well. Scripts whose programs cannot be determined
+/// statically, including ones that start another shell, fail with
+/// [`SandboxErr::UncheckableScript`] while a policy is configured. So does
+/// any other program given `-c` or `-lc` (e.g. `dash -c`), since its script
+/// cannot be parsed. Shell builtins such as `cd` or `echo` inside a parsed
+/// script need not be on the allowlist, but the denylist still applies.
+pub(crate) fn ensure_program_allowed(
+    command: &[String],
+    program_policy: &ProgramPolicy,
+) -> Result<()> {
+    if program_policy.denied_programs.is_empty() && program_policy.allowed_programs.is_none() {
+        return Ok(());
+    }
+    let Some(program) = command.first() else {
+        return Ok(());
+    };
+    ensure_basename_allowed(program, program_policy)?;
+
+    let [_, flag, script, ..] = command else {
+        return Ok(());
+    };
+    if !matches!(flag.as_str(), "-c" | "-lc") {
+        return Ok(());
+    }
+    let uncheckable = || {
+        CodexErr::Sandbox(SandboxErr::UncheckableScript {
+            shell: program_basename(program),
+        })
+    };
+    if !is_shell_program(program) {
+        return Err(uncheckable());
+    }
+    let names = try_parse_command_names(script).ok_or_else(uncheckable)?;
+    for name in names {
+        if starts_shell(&name) {
+            return Err(uncheckable());
+        }
+        if is_shell_builtin(&name) {
+            ensure_basename_not_denied(&name, program_policy)?;
+        } else {
+            ensure_basename_allowed(&name, program_policy)?;
+        }
+    }
+    Ok(())
+}
+
+fn ensure_basename_allowed(program: &str, program_policy: &ProgramPolicy) -> Result<()> {
+    ensure_basename_not_denied(program, program_policy)?;
+    let basename = program_basename(program);
+    if let Some(allowed) = &program_policy.allowed_programs
+        && !allowed.iter().any(|program| *program == basename)
+    {
+        return Err(CodexErr::Sandbox(SandboxErr::ProgramNotAllowed {
+            program: basename,
+        }));
+    }
+    Ok(())
+}
+
+fn ensure_basename_not_denied(program: &str, program_policy: &ProgramPolicy) -> Result<()> {
+    let basename = program_basename(program);
+    if program_policy
+        .denied_programs
+        .iter()
+        .any(|denied| *denied == basename)
+    {
+        return Err(CodexErr::Sandbox(SandboxErr::ProgramDenied {
+            program: basename,
+        }));
+    }
+    Ok(())
+}
+
+fn program_basename(program: &str) -> String {
+    Path::new(program)
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| program.to_string())
+}
+
+/// Shells whose `-c` scripts [`ensure_program_allowed`] can parse.
+fn is_shell_program(program: &str) -> bool {
+    matches!(program_basename(program).as_str(), "bash" | "zsh" | "sh")
+}
+
+/// Builtins that run inside the shell itself rather than starting a program.
+/// Builtins that run other commands, like `eval`, `exec` or `source`, are
+/// deliberately left out.
+fn is_shell_builtin(name: &str) -> bool {
+    matches!(
+        name,
+        "cd" | "echo"
+            | "printf"
+            | "pwd"
+            | "export"
+            | "unset"
+            | "set"
+            | "true"
+            | "false"
+            | "test"
+            | "["
+            | ":"
+            | "exit"
+            | "read"
+            | "shift"
+            | "local"
+    )
+}
+
+/// Programs that run a nested script whose commands a parsed script does not
+/// reveal.
+fn starts_shell(program: &str) -> bool {
+    is_shell_program(program)
+        || matches!(
+            program_basename(program).as_str(),
+            "dash" | "ash" | "ksh" | "mksh" | "fish" | "csh" | "tcsh" | "busybox"
+        )
+}
+
+/// What [`process_exec_tool_call`] would run for a given set of params,
+/// computed without spawning anything.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExecPlan {
+    /// Final argv, including any sandbox wrapper.
+    pub command: Vec<String>,
+    pub cwd: PathBuf,
+    /// Effective environment with sensitive values redacted.
+    pub env: HashMap<String, String>,
+    pub sandbox: SandboxType,
+    pub network_access: bool,
+    /// Writable roots granted by the sandbox policy; empty for read-only
+    /// policies and for `DangerFullAccess`.
+    pub writable_roots: Vec<PathBuf>,
+}
+
+/// Resolve `params` the same way [`process_exec_tool_call`] does and return
+/// the resulting [`ExecPlan`]. `env_policy` only controls redaction; the env
+/// itself is taken from `params.env`.
+pub fn plan_exec(
+    params: &ExecParams,
+    sandbox_type: SandboxType,
+    sandbox_policy: &SandboxPolicy,
+    sandbox_cwd: &Path,
+    codex_linux_sandbox_exe: &Option<PathBuf>,
+    env_policy: &ShellEnvironmentPolicy,
+) -> Result<ExecPlan> {
+    if params.require_cwd_in_writable_roots {
+        ensure_cwd_in_writable_roots(&params.cwd, sandbox_policy, sandbox_cwd)?;
+    }
+    ensure_program_allowed(&params.command, &params.program_policy)?;
+
+    let (program, args) = params.command.split_first().ok_or_else(|| {
+        CodexErr::Io(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "command args are empty",
+        ))
+    })?;
+
+    let spec = CommandSpec {
+        program: program.clone(),
+        args: args.to_vec(),
+        cwd: params.cwd.clone(),
+        env: params.env.clone(),
+        timeout_ms: params.timeout_ms,
+        with_escalated_permissions: params.with_escalated_permissions,
+        justification: params.justification.clone(),
+        capture_output_lines: params.capture_output_lines,
+        stop_on_first_stdout_byte: params.stop_on_first_stdout_byte,
+        // The plan does not report stdin, so skip copying it.
+        stdin: None,
+        merge_stderr_into_stdout: params.merge_stderr_into_stdout,
+        umask: params.umask,
+        env_policy: params.env_policy.clone(),
+    };
+
+    let exec_env = SandboxManager::new()
+        .transform(
+            &spec,
+            sandbox_policy,
+            sandbox_type,
+            sandbox_cwd,
+            codex_linux_sandbox_exe.as_ref(),
+        )
+        .map_err(CodexErr::from)?;
+
+    let writable_roots = match sandbox_policy {
+        SandboxPolicy::WorkspaceWrite { .. } => sandbox_policy
+            .get_writable_roots_with_cwd(sandbox_cwd)
+            .into_iter()
+            .map(|writable_root| writable_root.root)
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    Ok(ExecPlan {
+        command: exec_env.command,
+        cwd: exec_env.cwd,
+        env: redact_env(&exec_env.env, env_policy),
+        sandbox: exec_env.sandbox,
+        network_access: sandbox_policy.has_full_network_access(),
+        writable_roots,
+    })
 }
 
 pub(crate) async fn execute_exec_env(
     env: ExecEnv,
     sandbox_policy: &SandboxPolicy,
     stdout_stream: Option<StdoutStream>,
+    cancellation_token: Option<CancellationToken>,
 ) -> Result<ExecToolCallOutput> {
     let ExecEnv {
         command,
@@ -148,6 +520,12 @@ pub(crate) async fn execute_exec_env(
         with_escalated_permissions,
         justification,
         arg0,
+        capture_output_lines,
+        stop_on_first_stdout_byte,
+        stdin,
+        merge_stderr_into_stdout,
+        umask,
+        env_policy,
     } = env;
 
     let params = ExecParams {
@@ -158,10 +536,26 @@ pub(crate) async fn execute_exec_env(
         with_escalated_permissions,
         justification,
         arg0,
+        require_cwd_in_writable_roots: false,
+        allocate_pty: false,
+        capture_output_lines,
+        stop_on_first_stdout_byte,
+        stdin,
+        merge_stderr_into_stdout,
+        umask,
+        program_policy: Default::default(),
+        env_policy,
     };
 
     let start = Instant::now();
-    let raw_output_result = exec(params, sandbox, sandbox_policy, stdout_stream).await;
+    let raw_output_result = exec(
+        params,
+        sandbox,
+        sandbox_policy,
+        stdout_stream,
+        cancellation_token,
+    )
+    .await;
     let duration = start.elapsed();
     finalize_exec_result(raw_output_result, sandbox, duration)
 }
@@ -182,6 +576,13 @@ async fn exec_windows_sandbox(
         ..
     } = params;
 
+    // Resolve the timeout like `timeout_duration`: `Some(0)` means no
+    // timeout, which the Windows sandbox spells `None`.
+    let timeout_ms = match timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS) {
+        0 => None,
+        ms => Some(ms),
+    };
+
     let policy_str = match sandbox_policy {
         SandboxPolicy::DangerFullAccess => "workspace-write",
         SandboxPolicy::ReadOnly => "read-only",
@@ -241,6 +642,9 @@ async fn exec_windows_sandbox(
         stderr,
         aggregated_output,
         timed_out: capture.timed_out,
+        peak_rss_bytes: None,
+        output_lines: Vec::new(),
+        stopped_early: false,
     })
 }
 
@@ -280,6 +684,9 @@ fn finalize_exec_result(
                 aggregated_output,
                 duration,
                 timed_out,
+                peak_rss_bytes: raw_output.peak_rss_bytes,
+                output_lines: raw_output.output_lines,
+                stopped_early: raw_output.stopped_early,
             };
 
             if timed_out {
@@ -305,6 +712,7 @@ fn finalize_exec_result(
 
 pub(crate) mod errors {
     use super::CodexErr;
+    use super::SandboxErr;
     use crate::sandboxing::SandboxTransformError;
 
     impl From<SandboxTransformError> for CodexErr {
@@ -313,6 +721,9 @@ pub(crate) mod errors {
                 SandboxTransformError::MissingLinuxSandboxExecutable => {
                     CodexErr::LandlockSandboxExecutableNotProvided
                 }
+                SandboxTransformError::LinuxSandboxExecutableNotFound(path) => {
+                    CodexErr::Sandbox(SandboxErr::HelperMissing { path })
+                }
                 #[cfg(not(target_os = "macos"))]
                 SandboxTransformError::SeatbeltUnavailable => CodexErr::UnsupportedOperation(
                     "seatbelt sandbox is only available on macOS".to_string(),
@@ -397,6 +808,9 @@ struct RawExecToolCallOutput {
     pub stderr: StreamOutput<Vec<u8>>,
     pub aggregated_output: StreamOutput<Vec<u8>>,
     pub timed_out: bool,
+    pub peak_rss_bytes: Option<u64>,
+    pub output_lines: Vec<OutputLine>,
+    pub stopped_early: bool,
 }
 
 impl StreamOutput<String> {
@@ -430,6 +844,23 @@ pub struct ExecToolCallOutput {
     pub aggregated_output: StreamOutput<String>,
     pub duration: Duration,
     pub timed_out: bool,
+    /// Peak resident set size of the command, when the platform reports it.
+    pub peak_rss_bytes: Option<u64>,
+    /// Output lines from both streams in the order they were read. Only
+    /// populated when `ExecParams::capture_output_lines` is set.
+    pub output_lines: Vec<OutputLine>,
+    /// True when the command was killed after its first stdout output because
+    /// `ExecParams::stop_on_first_stdout_byte` was set.
+    pub stopped_early: bool,
+}
+
+/// A single line of command output, tagged with the stream it came from and
+/// the time it was read.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OutputLine {
+    pub stream: ExecOutputStream,
+    pub at: Instant,
+    pub text: String,
 }
 
 #[cfg_attr(not(target_os = "windows"), allow(unused_variables))]
@@ -438,6 +869,7 @@ async fn exec(
     sandbox: SandboxType,
     sandbox_policy: &SandboxPolicy,
     stdout_stream: Option<StdoutStream>,
+    cancellation_token: Option<CancellationToken>,
 ) -> Result<RawExecToolCallOutput> {
     #[cfg(target_os = "windows")]
     if sandbox == SandboxType::WindowsRestrictedToken {
@@ -449,6 +881,12 @@ async fn exec(
         cwd,
         env,
         arg0,
+        capture_output_lines,
+        stop_on_first_stdout_byte,
+        stdin,
+        merge_stderr_into_stdout,
+        umask,
+        env_policy,
         ..
     } = params;
 
@@ -459,25 +897,199 @@ async fn exec(
         ))
     })?;
     let arg0_ref = arg0.as_deref();
-    let child = spawn_child_async(
+    let stdio_policy = if stdin.is_some() || merge_stderr_into_stdout {
+        StdioPolicy::RedirectForShellToolWith {
+            pipe_stdin: stdin.is_some(),
+            merge_stderr: merge_stderr_into_stdout,
+        }
+    } else {
+        StdioPolicy::RedirectForShellTool
+    };
+    // Spawning also fails with `NotFound` when `cwd` is missing, so only
+    // blame the program when the directory exists.
+    let cwd_exists = cwd.is_dir();
+    let mut child = spawn_child_async(
         PathBuf::from(program),
         args.into(),
         arg0_ref,
         cwd,
         sandbox_policy,
-        StdioPolicy::RedirectForShellTool,
+        stdio_policy,
         env,
+        &env_policy,
+        umask,
+    )
+    .await
+    .map_err(|err| {
+        if err.kind() == io::ErrorKind::NotFound && cwd_exists {
+            CodexErr::CommandNotFound {
+                program: program.clone(),
+            }
+        } else {
+            CodexErr::Io(err)
+        }
+    })?;
+    if let Some(input) = stdin {
+        write_stdin(&mut child, input)?;
+    }
+    consume_truncated_output(
+        child,
+        timeout,
+        stdout_stream,
+        cancellation_token,
+        capture_output_lines,
+        stop_on_first_stdout_byte,
+        merge_stderr_into_stdout,
     )
-    .await?;
-    consume_truncated_output(child, timeout, stdout_stream).await
+    .await
+}
+
+/// Writes `input` to the child's stdin in the background and then closes it,
+/// so a command that produces output before reading all of its input cannot
+/// deadlock against the output readers. A child that exits without reading
+/// everything (`BrokenPipe`) is not an error.
+fn write_stdin(child: &mut Child, input: Vec<u8>) -> Result<()> {
+    let mut child_stdin = child.stdin.take().ok_or_else(|| {
+        CodexErr::Io(io::Error::other(
+            "stdin pipe was unexpectedly not available",
+        ))
+    })?;
+    tokio::spawn(async move {
+        if let Err(err) = child_stdin.write_all(&input).await
+            && err.kind() != io::ErrorKind::BrokenPipe
+        {
+            tracing::warn!("failed to write exec stdin: {err}");
+        }
+        // Dropping the handle closes the pipe so the child sees EOF.
+    });
+    Ok(())
+}
+
+/// Runs the command attached to a pseudo-terminal. A PTY merges stdout and
+/// stderr, so the combined output is reported as both stdout and the
+/// aggregated output.
+#[cfg(unix)]
+async fn exec_in_pty(
+    env: ExecEnv,
+    cancellation_token: Option<CancellationToken>,
+) -> Result<RawExecToolCallOutput> {
+    enum PtyOutcome {
+        Exited(i32),
+        TimedOut,
+        Cancelled,
+    }
+
+    let timeout = timeout_duration(env.timeout_ms);
+    let ExecEnv {
+        command,
+        cwd,
+        env,
+        arg0,
+        ..
+    } = env;
+    let (program, args) = command.split_first().ok_or_else(|| {
+        CodexErr::Io(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "command args are empty",
+        ))
+    })?;
+    let codex_utils_pty::SpawnedPty {
+        session,
+        mut output_rx,
+        mut exit_rx,
+    } = codex_utils_pty::spawn_pty_process(program, args, &cwd, &env, &arg0)
+        .await
+        .map_err(|err| CodexErr::Io(io::Error::other(err.to_string())))?;
+
+    let mut output = Vec::with_capacity(AGGREGATE_BUFFER_INITIAL_CAPACITY);
+    let mut output_open = true;
+    let deadline = tokio::time::sleep(timeout);
+    tokio::pin!(deadline);
+
+    let outcome = loop {
+        tokio::select! {
+            chunk = output_rx.recv(), if output_open => match chunk {
+                Ok(chunk) => append_all(&mut output, &chunk),
+                Err(RecvError::Lagged(_)) => {}
+                Err(RecvError::Closed) => output_open = false,
+            },
+            code = &mut exit_rx => break PtyOutcome::Exited(code.unwrap_or(-1)),
+            _ = &mut deadline => break PtyOutcome::TimedOut,
+            _ = wait_for_cancellation(cancellation_token.as_ref()) => break PtyOutcome::Cancelled,
+        }
+    };
+
+    let (exit_status, timed_out) = match outcome {
+        PtyOutcome::Exited(code) => {
+            // The reader may still hold output written just before exit.
+            while let Ok(Ok(chunk)) =
+                tokio::time::timeout(PTY_OUTPUT_DRAIN_TIMEOUT, output_rx.recv()).await
+            {
+                append_all(&mut output, &chunk);
+            }
+            // Encode a normal exit in the raw wait status.
+            (ExitStatus::from_raw((code & 0xff) << 8), false)
+        }
+        PtyOutcome::TimedOut => (
+            synthetic_exit_status(EXIT_CODE_SIGNAL_BASE + TIMEOUT_CODE),
+            true,
+        ),
+        PtyOutcome::Cancelled => {
+            // Dropping the session kills the child.
+            drop(session);
+            return Err(CodexErr::Sandbox(SandboxErr::Cancelled));
+        }
+    };
+    drop(session);
+
+    Ok(RawExecToolCallOutput {
+        exit_status,
+        stdout: StreamOutput {
+            text: output.clone(),
+            truncated_after_lines: None,
+        },
+        stderr: StreamOutput {
+            text: Vec::new(),
+            truncated_after_lines: None,
+        },
+        aggregated_output: StreamOutput {
+            text: output,
+            truncated_after_lines: None,
+        },
+        timed_out,
+        peak_rss_bytes: None,
+        output_lines: Vec::new(),
+        stopped_early: false,
+    })
+}
+
+#[cfg(not(unix))]
+async fn exec_in_pty(
+    _env: ExecEnv,
+    _cancellation_token: Option<CancellationToken>,
+) -> Result<RawExecToolCallOutput> {
+    Err(CodexErr::Io(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "allocate_pty is only supported on Unix",
+    )))
 }
 
 /// Consumes the output of a child process, truncating it so it is suitable for
-/// use as the output of a `shell` tool call. Also enforces specified timeout.
+/// use as the output of a `shell` tool call. Also enforces specified timeout
+/// and stops the child's process group when `cancellation_token` fires. When
+/// `capture_output_lines` is set, lines from both streams are also collected in
+/// chronological order. When `stop_on_first_stdout_byte` is set, the process
+/// group is killed once stdout produces data and the run reports exit code 0.
+/// With `merge_stderr_into_stdout` the child may have no stderr pipe, in which
+/// case stderr is reported empty.
 async fn consume_truncated_output(
     mut child: Child,
     timeout: Duration,
     stdout_stream: Option<StdoutStream>,
+    cancellation_token: Option<CancellationToken>,
+    capture_output_lines: bool,
+    stop_on_first_stdout_byte: bool,
+    merge_stderr_into_stdout: bool,
 ) -> Result<RawExecToolCallOutput> {
     // Both stdout and stderr were configured with `Stdio::piped()`
     // above, therefore `take()` should normally return `Some`.  If it doesn't
@@ -488,48 +1100,122 @@ async fn consume_truncated_output(
             "stdout pipe was unexpectedly not available",
         ))
     })?;
-    let stderr_reader = child.stderr.take().ok_or_else(|| {
-        CodexErr::Io(io::Error::other(
+    let stderr_reader = child.stderr.take();
+    if stderr_reader.is_none() && !merge_stderr_into_stdout {
+        return Err(CodexErr::Io(io::Error::other(
             "stderr pipe was unexpectedly not available",
-        ))
-    })?;
+        )));
+    }
 
     let (agg_tx, agg_rx) = async_channel::unbounded::<Vec<u8>>();
+    let (line_tx, line_rx) = if capture_output_lines {
+        let (tx, rx) = async_channel::unbounded::<OutputLine>();
+        (Some(tx), Some(rx))
+    } else {
+        (None, None)
+    };
+
+    let first_stdout = stop_on_first_stdout_byte.then(|| Arc::new(Notify::new()));
 
     let stdout_handle = tokio::spawn(read_capped(
         BufReader::new(stdout_reader),
         stdout_stream.clone(),
         false,
         Some(agg_tx.clone()),
+        line_tx.clone(),
+        first_stdout.clone(),
     ));
-    let stderr_handle = tokio::spawn(read_capped(
-        BufReader::new(stderr_reader),
-        stdout_stream.clone(),
-        true,
-        Some(agg_tx.clone()),
-    ));
-
-    let (exit_status, timed_out) = tokio::select! {
-        result = tokio::time::timeout(timeout, child.wait()) => {
-            match result {
-                Ok(status_result) => {
-                    let exit_status = status_result?;
-                    (exit_status, false)
+    let stderr_handle = match stderr_reader {
+        Some(stderr_reader) => tokio::spawn(read_capped(
+            BufReader::new(stderr_reader),
+            stdout_stream.clone(),
+            true,
+            Some(agg_tx.clone()),
+            line_tx.clone(),
+            None,
+        )),
+        None => tokio::spawn(async {
+            Ok::<_, io::Error>(StreamOutput {
+                text: Vec::new(),
+                truncated_after_lines: None,
+            })
+        }),
+    };
+    drop(line_tx);
+
+    let control_rx = stdout_stream
+        .as_ref()
+        .and_then(|stream| stream.control_rx.clone());
+    let deadline = tokio::time::sleep(timeout);
+    tokio::pin!(deadline);
+    // Started once, outside the loop: the blocking `waitid` cannot be
+    // cancelled, so starting one per iteration would leave a thread parked
+    // until the child exits for every pause/resume request.
+    let mut peak_rss_wait = spawn_peak_rss_wait(&child);
+
+    let mut stopped_early = false;
+    let (exit_status, timed_out, peak_rss_bytes) = loop {
+        tokio::select! {
+            status_result = child.wait(), if peak_rss_wait.is_none() => {
+                break (status_result?, false, None);
+            }
+            peak_rss_bytes = wait_for_peak_rss(&mut peak_rss_wait),
+                if peak_rss_wait.is_some() =>
+            {
+                // The blocking wait leaves the exited child unreaped.
+                let exit_status = child.wait().await?;
+                break (exit_status, false, peak_rss_bytes);
+            }
+            _ = wait_for_notify(first_stdout.as_deref()) => {
+                kill_child_process_group(&mut child)?;
+                child.start_kill()?;
+                // Reap the child; the readers finish once the killed group
+                // closes its end of the pipes.
+                child.wait().await?;
+                stopped_early = true;
+                break (synthetic_exit_status(0), false, None);
+            }
+            _ = &mut deadline => {
+                kill_child_process_group(&mut child)?;
+                child.start_kill()?;
+                // Debatable whether `child.wait().await` should be called here.
+                break (synthetic_exit_status(EXIT_CODE_SIGNAL_BASE + TIMEOUT_CODE), true, None);
+            }
+            _ = tokio::signal::ctrl_c() => {
+                kill_child_process_group(&mut child)?;
+                child.start_kill()?;
+                break (synthetic_exit_status(EXIT_CODE_SIGNAL_BASE + SIGKILL_CODE), false, None);
+            }
+            _ = wait_for_cancellation(cancellation_token.as_ref()) => {
+                kill_child_process_group(&mut child)?;
+                child.start_kill()?;
+                // Reap the child so a cancelled command does not linger as a zombie.
+                child.wait().await?;
+                return Err(CodexErr::Sandbox(SandboxErr::Cancelled));
+            }
+            Some(request) = next_exec_control(control_rx.as_ref()) => {
+                if let Err(err) = signal_exec_control(&child, request) {
+                    tracing::warn!("failed to apply {request:?} to exec child: {err}");
+                    continue;
                 }
-                Err(_) => {
-                    // timeout
-                    kill_child_process_group(&mut child)?;
-                    child.start_kill()?;
-                    // Debatable whether `child.wait().await` should be called here.
-                    (synthetic_exit_status(EXIT_CODE_SIGNAL_BASE + TIMEOUT_CODE), true)
+                if let Some(stream) = &stdout_stream {
+                    let call_id = stream.call_id.clone();
+                    let msg = match request {
+                        ExecControl::Pause => {
+                            EventMsg::ExecCommandPaused(ExecCommandPausedEvent { call_id })
+                        }
+                        ExecControl::Resume => {
+                            EventMsg::ExecCommandResumed(ExecCommandResumedEvent { call_id })
+                        }
+                    };
+                    let event = Event {
+                        id: stream.sub_id.clone(),
+                        msg,
+                    };
+                    let _ = stream.tx_event.send(event).await;
                 }
             }
         }
-        _ = tokio::signal::ctrl_c() => {
-            kill_child_process_group(&mut child)?;
-            child.start_kill()?;
-            (synthetic_exit_status(EXIT_CODE_SIGNAL_BASE + SIGKILL_CODE), false)
-        }
     };
 
     let stdout = stdout_handle.await??;
@@ -546,24 +1232,98 @@ async fn consume_truncated_output(
         truncated_after_lines: None,
     };
 
+    let mut output_lines = Vec::new();
+    if let Some(line_rx) = line_rx {
+        while let Ok(line) = line_rx.recv().await {
+            output_lines.push(line);
+        }
+        // Each reader sends in order, but the two streams can interleave on
+        // the channel; the stable sort restores arrival order across streams.
+        output_lines.sort_by_key(|line| line.at);
+    }
+
     Ok(RawExecToolCallOutput {
         exit_status,
         stdout,
         stderr,
         aggregated_output,
         timed_out,
+        peak_rss_bytes,
+        output_lines,
+        stopped_early,
     })
 }
 
+/// Starts a blocking wait that resolves to the child's peak RSS once it has
+/// exited. `None` where peak RSS is unavailable; callers then wait on the
+/// child directly.
+#[cfg(target_os = "linux")]
+fn spawn_peak_rss_wait(child: &Child) -> Option<JoinHandle<Option<u64>>> {
+    let pid = child.id()?;
+    Some(tokio::task::spawn_blocking(move || {
+        peak_rss_after_exit(pid)
+    }))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn spawn_peak_rss_wait(_: &Child) -> Option<JoinHandle<Option<u64>>> {
+    None
+}
+
+/// Resolves when the wait started by [`spawn_peak_rss_wait`] finishes. Polls
+/// the same task by reference, so it is safe to call once per `select!` loop
+/// iteration.
+async fn wait_for_peak_rss(peak_rss_wait: &mut Option<JoinHandle<Option<u64>>>) -> Option<u64> {
+    match peak_rss_wait {
+        Some(handle) => handle.await.ok().flatten(),
+        None => std::future::pending().await,
+    }
+}
+
+/// Blocks until `pid` exits and reads its rusage without reaping it, leaving
+/// the zombie for tokio's `Child::wait` to collect.
+#[cfg(target_os = "linux")]
+fn peak_rss_after_exit(pid: u32) -> Option<u64> {
+    let mut info: libc::siginfo_t = unsafe { std::mem::zeroed() };
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    // glibc's `waitid` wrapper does not expose the rusage argument of the
+    // underlying syscall, so invoke it directly.
+    let result = unsafe {
+        libc::syscall(
+            libc::SYS_waitid,
+            libc::P_PID,
+            pid as libc::id_t,
+            &mut info as *mut libc::siginfo_t,
+            libc::WEXITED | libc::WNOWAIT,
+            &mut usage as *mut libc::rusage,
+        )
+    };
+    if result == -1 {
+        return None;
+    }
+    // `ru_maxrss` is reported in kilobytes on Linux.
+    u64::try_from(usage.ru_maxrss)
+        .ok()
+        .map(|kilobytes| kilobytes * 1024)
+}
+
 async fn read_capped<R: AsyncRead + Unpin + Send + 'static>(
     mut reader: R,
     stream: Option<StdoutStream>,
     is_stderr: bool,
     aggregate_tx: Option<Sender<Vec<u8>>>,
+    line_tx: Option<Sender<OutputLine>>,
+    first_output: Option<Arc<Notify>>,
 ) -> io::Result<StreamOutput<Vec<u8>>> {
     let mut buf = Vec::with_capacity(AGGREGATE_BUFFER_INITIAL_CAPACITY);
     let mut tmp = [0u8; READ_CHUNK_SIZE];
     let mut emitted_deltas: usize = 0;
+    let mut pending_line = Vec::new();
+    let output_stream = if is_stderr {
+        ExecOutputStream::Stderr
+    } else {
+        ExecOutputStream::Stdout
+    };
 
     // No caps: append all bytes
 
@@ -573,17 +1333,19 @@ async fn read_capped<R: AsyncRead + Unpin + Send + 'static>(
             break;
         }
 
+        if let Some(first_output) = first_output.as_ref()
+            && buf.is_empty()
+        {
+            first_output.notify_one();
+        }
+
         if let Some(stream) = &stream
             && emitted_deltas < MAX_EXEC_OUTPUT_DELTAS_PER_CALL
         {
             let chunk = tmp[..n].to_vec();
             let msg = EventMsg::ExecCommandOutputDelta(ExecCommandOutputDeltaEvent {
                 call_id: stream.call_id.clone(),
-                stream: if is_stderr {
-                    ExecOutputStream::Stderr
-                } else {
-                    ExecOutputStream::Stdout
-                },
+                stream: output_stream.clone(),
                 chunk,
             });
             let event = Event {
@@ -599,16 +1361,49 @@ async fn read_capped<R: AsyncRead + Unpin + Send + 'static>(
             let _ = tx.send(tmp[..n].to_vec()).await;
         }
 
+        if let Some(tx) = &line_tx {
+            let at = Instant::now();
+            pending_line.extend_from_slice(&tmp[..n]);
+            while let Some(pos) = pending_line.iter().position(|byte| *byte == b'\n') {
+                let line: Vec<u8> = pending_line.drain(..=pos).collect();
+                let _ = tx
+                    .send(OutputLine {
+                        stream: output_stream.clone(),
+                        at,
+                        text: line_text(&line[..pos]),
+                    })
+                    .await;
+            }
+        }
+
         append_all(&mut buf, &tmp[..n]);
         // Continue reading to EOF to avoid back-pressure
     }
 
+    if let Some(tx) = &line_tx
+        && !pending_line.is_empty()
+    {
+        let _ = tx
+            .send(OutputLine {
+                stream: output_stream,
+                at: Instant::now(),
+                text: line_text(&pending_line),
+            })
+            .await;
+    }
+
     Ok(StreamOutput {
         text: buf,
         truncated_after_lines: None,
     })
 }
 
+/// Decodes a captured line, dropping the `\r` of a CRLF terminator.
+fn line_text(line: &[u8]) -> String {
+    let line = line.strip_suffix(b"\r").unwrap_or(line);
+    String::from_utf8_lossy(line).into_owned()
+}
+
 #[cfg(unix)]
 fn synthetic_exit_status(code: i32) -> ExitStatus {
     use std::os::unix::process::ExitStatusExt;
@@ -623,6 +1418,59 @@ fn synthetic_exit_status(code: i32) -> ExitStatus {
     std::process::ExitStatus::from_raw(code as u32)
 }
 
+/// Resolves once `notify` is notified, or never when none was provided.
+async fn wait_for_notify(notify: Option<&Notify>) {
+    match notify {
+        Some(notify) => notify.notified().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Resolves once `token` is cancelled, or never when no token was provided.
+async fn wait_for_cancellation(token: Option<&CancellationToken>) {
+    match token {
+        Some(token) => token.cancelled().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Resolves with the next control request, or never when no control channel
+/// was provided. A closed channel yields `None`, disabling the select branch.
+async fn next_exec_control(control_rx: Option<&Receiver<ExecControl>>) -> Option<ExecControl> {
+    match control_rx {
+        Some(rx) => rx.recv().await.ok(),
+        None => std::future::pending().await,
+    }
+}
+
+#[cfg(unix)]
+fn signal_exec_control(child: &Child, request: ExecControl) -> io::Result<()> {
+    let Some(pid) = child.id() else {
+        return Ok(());
+    };
+    let signal = match request {
+        ExecControl::Pause => libc::SIGSTOP,
+        ExecControl::Resume => libc::SIGCONT,
+    };
+    let pid = pid as libc::pid_t;
+    let pgid = unsafe { libc::getpgid(pid) };
+    if pgid == -1 {
+        return Err(std::io::Error::last_os_error());
+    }
+    if unsafe { libc::killpg(pgid, signal) } == -1 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn signal_exec_control(_: &Child, _: ExecControl) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "pausing commands is only supported on Unix",
+    ))
+}
+
 #[cfg(unix)]
 fn kill_child_process_group(child: &mut Child) -> io::Result<()> {
     use std::io::ErrorKind;
@@ -658,8 +1506,34 @@ fn kill_child_process_group(_: &mut Child) -> io::Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::types::EnvironmentVariablePattern;
+    use pretty_assertions::assert_eq;
     use std::time::Duration;
 
+    /// [`ExecParams`] running `command` in the current directory with the
+    /// test's environment and a 5s timeout; tests override the fields they
+    /// care about with struct update syntax.
+    fn exec_params(command: Vec<String>) -> ExecParams {
+        ExecParams {
+            command,
+            cwd: std::env::current_dir().expect("current dir"),
+            timeout_ms: Some(5_000),
+            env: std::env::vars().collect(),
+            with_escalated_permissions: None,
+            justification: None,
+            arg0: None,
+            require_cwd_in_writable_roots: false,
+            allocate_pty: false,
+            capture_output_lines: false,
+            stop_on_first_stdout_byte: false,
+            stdin: None,
+            merge_stderr_into_stdout: false,
+            umask: None,
+            program_policy: Default::default(),
+            env_policy: Default::default(),
+        }
+    }
+
     fn make_exec_output(
         exit_code: i32,
         stdout: &str,
@@ -673,6 +1547,9 @@ mod tests {
             aggregated_output: StreamOutput::new(aggregated.to_string()),
             duration: Duration::from_millis(1),
             timed_out: false,
+            peak_rss_bytes: None,
+            output_lines: Vec::new(),
+            stopped_early: false,
         }
     }
 
@@ -738,16 +1615,19 @@ mod tests {
         ];
         let env: HashMap<String, String> = std::env::vars().collect();
         let params = ExecParams {
-            command,
-            cwd: std::env::current_dir()?,
             timeout_ms: Some(500),
             env,
-            with_escalated_permissions: None,
-            justification: None,
-            arg0: None,
+            ..exec_params(command)
         };
 
-        let output = exec(params, SandboxType::None, &SandboxPolicy::ReadOnly, None).await?;
+        let output = exec(
+            params,
+            SandboxType::None,
+            &SandboxPolicy::ReadOnly,
+            None,
+            None,
+        )
+        .await?;
         assert!(output.timed_out);
 
         let stdout = output.stdout.from_utf8_lossy().text;
@@ -774,4 +1654,785 @@ mod tests {
         assert!(killed, "grandchild process with pid {pid} is still alive");
         Ok(())
     }
+
+    #[cfg(target_os = "linux")]
+    fn drain_stdout_deltas(rx_event: &Receiver<Event>, stdout: &mut String) {
+        while let Ok(event) = rx_event.try_recv() {
+            if let EventMsg::ExecCommandOutputDelta(delta) = event.msg {
+                stdout.push_str(&String::from_utf8_lossy(&delta.chunk));
+            }
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn pause_stops_progress_until_resumed() -> Result<()> {
+        let command = vec![
+            "/bin/bash".to_string(),
+            "-c".to_string(),
+            "for i in $(seq 1 20); do echo $i; sleep 0.05; done".to_string(),
+        ];
+        let params = ExecParams {
+            timeout_ms: Some(10_000),
+            ..exec_params(command)
+        };
+        let (tx_event, rx_event) = async_channel::unbounded();
+        let (control_tx, control_rx) = async_channel::unbounded();
+        let stream = StdoutStream {
+            sub_id: "sub".to_string(),
+            call_id: "call".to_string(),
+            tx_event,
+            control_rx: Some(control_rx),
+        };
+        let handle = tokio::spawn(async move {
+            exec(
+                params,
+                SandboxType::None,
+                &SandboxPolicy::ReadOnly,
+                Some(stream),
+                None,
+            )
+            .await
+        });
+
+        let mut stdout = String::new();
+        while stdout.lines().count() < 3 {
+            let event = rx_event.recv().await.expect("exec event");
+            if let EventMsg::ExecCommandOutputDelta(delta) = event.msg {
+                stdout.push_str(&String::from_utf8_lossy(&delta.chunk));
+            }
+        }
+
+        control_tx
+            .send(ExecControl::Pause)
+            .await
+            .expect("send pause");
+        loop {
+            let event = rx_event.recv().await.expect("exec event");
+            match event.msg {
+                EventMsg::ExecCommandPaused(paused) => {
+                    assert_eq!(paused.call_id, "call");
+                    break;
+                }
+                EventMsg::ExecCommandOutputDelta(delta) => {
+                    stdout.push_str(&String::from_utf8_lossy(&delta.chunk));
+                }
+                _ => {}
+            }
+        }
+
+        // Let any output written before the stop drain, then confirm the
+        // child makes no further progress while paused.
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        drain_stdout_deltas(&rx_event, &mut stdout);
+        let lines_while_paused = stdout.lines().count();
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        drain_stdout_deltas(&rx_event, &mut stdout);
+        assert_eq!(stdout.lines().count(), lines_while_paused);
+        assert!(lines_while_paused < 20);
+
+        control_tx
+            .send(ExecControl::Resume)
+            .await
+            .expect("send resume");
+        let output = handle.await.expect("exec task")?;
+
+        assert!(!output.timed_out);
+        assert_eq!(output.exit_status.code(), Some(0));
+        assert!(output.stdout.from_utf8_lossy().text.ends_with("20\n"));
+        let mut resumed = false;
+        while let Ok(event) = rx_event.try_recv() {
+            if let EventMsg::ExecCommandResumed(event) = event.msg {
+                assert_eq!(event.call_id, "call");
+                resumed = true;
+            }
+        }
+        assert!(resumed, "expected an ExecCommandResumed event");
+        Ok(())
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn reports_peak_rss_for_allocating_command() -> Result<()> {
+        let command = vec![
+            "/bin/bash".to_string(),
+            "-c".to_string(),
+            "buf=$(head -c 16000000 /dev/zero | tr '\\0' a); echo ${#buf}".to_string(),
+        ];
+        let params = ExecParams {
+            timeout_ms: Some(10_000),
+            ..exec_params(command)
+        };
+
+        let output = exec(
+            params,
+            SandboxType::None,
+            &SandboxPolicy::ReadOnly,
+            None,
+            None,
+        )
+        .await?;
+
+        assert_eq!(output.exit_status.code(), Some(0));
+        assert_eq!(output.stdout.from_utf8_lossy().text, "16000000\n");
+        let peak_rss_bytes = output.peak_rss_bytes.expect("peak RSS on Linux");
+        assert!(
+            peak_rss_bytes >= 16_000_000,
+            "expected at least 16MB peak RSS, got {peak_rss_bytes}"
+        );
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn cancellation_stops_command_promptly() -> Result<()> {
+        let params = ExecParams {
+            timeout_ms: Some(10_000),
+            ..exec_params(vec!["sleep".to_string(), "5".to_string()])
+        };
+        let cancellation_token = CancellationToken::new();
+        let canceller = cancellation_token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            canceller.cancel();
+        });
+
+        let start = Instant::now();
+        let cwd = std::env::current_dir()?;
+        let result = process_exec_tool_call(
+            params,
+            SandboxType::None,
+            &SandboxPolicy::DangerFullAccess,
+            cwd.as_path(),
+            &None,
+            None,
+            Some(cancellation_token),
+        )
+        .await;
+
+        assert!(
+            matches!(result, Err(CodexErr::Sandbox(SandboxErr::Cancelled))),
+            "expected Cancelled, got {result:?}"
+        );
+        assert!(start.elapsed() < Duration::from_secs(2));
+        Ok(())
+    }
+
+    fn workspace_write_policy() -> SandboxPolicy {
+        SandboxPolicy::WorkspaceWrite {
+            writable_roots: Vec::new(),
+            network_access: false,
+            exclude_tmpdir_env_var: true,
+            exclude_slash_tmp: true,
+        }
+    }
+
+    #[test]
+    fn cwd_inside_writable_root_passes_check() -> Result<()> {
+        let workspace = tempfile::tempdir()?;
+        let nested = workspace.path().join("nested");
+
+        ensure_cwd_in_writable_roots(&nested, &workspace_write_policy(), workspace.path())?;
+        ensure_cwd_in_writable_roots(
+            Path::new("relative/dir"),
+            &workspace_write_policy(),
+            workspace.path(),
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn cwd_escaping_writable_root_through_parent_dir_fails_check() -> Result<()> {
+        let workspace = tempfile::tempdir()?;
+        let escaping = workspace.path().join("nested").join("..").join("..");
+
+        let result =
+            ensure_cwd_in_writable_roots(&escaping, &workspace_write_policy(), workspace.path());
+        assert!(
+            matches!(
+                result,
+                Err(CodexErr::Sandbox(
+                    SandboxErr::CwdOutsideWritableRoots { .. }
+                ))
+            ),
+            "expected CwdOutsideWritableRoots, got {result:?}"
+        );
+        ensure_cwd_in_writable_roots(
+            Path::new("nested/../still-inside"),
+            &workspace_write_policy(),
+            workspace.path(),
+        )?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cwd_outside_writable_roots_fails_before_spawn() -> Result<()> {
+        let workspace = tempfile::tempdir()?;
+        let elsewhere = tempfile::tempdir()?;
+        let marker = elsewhere.path().join("marker");
+        let params = ExecParams {
+            cwd: elsewhere.path().to_path_buf(),
+            timeout_ms: Some(1_000),
+            require_cwd_in_writable_roots: true,
+            ..exec_params(vec![
+                "touch".to_string(),
+                marker.to_string_lossy().to_string(),
+            ])
+        };
+
+        let result = process_exec_tool_call(
+            params,
+            SandboxType::None,
+            &workspace_write_policy(),
+            workspace.path(),
+            &None,
+            None,
+            None,
+        )
+        .await;
+
+        match result {
+            Err(CodexErr::Sandbox(SandboxErr::CwdOutsideWritableRoots { cwd })) => {
+                assert_eq!(cwd, elsewhere.path().to_path_buf());
+            }
+            other => panic!("expected CwdOutsideWritableRoots, got {other:?}"),
+        }
+        assert!(!marker.exists());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn missing_linux_sandbox_helper_is_reported_before_spawn() -> Result<()> {
+        let cwd = tempfile::tempdir()?;
+        let helper = cwd.path().join("codex-linux-sandbox");
+        assert!(!crate::sandboxing::is_available(
+            SandboxType::LinuxSeccomp,
+            Some(&helper)
+        ));
+
+        let params = ExecParams {
+            cwd: cwd.path().to_path_buf(),
+            timeout_ms: Some(1_000),
+            env: HashMap::new(),
+            ..exec_params(vec!["true".to_string()])
+        };
+        let result = process_exec_tool_call(
+            params,
+            SandboxType::LinuxSeccomp,
+            &SandboxPolicy::new_read_only_policy(),
+            cwd.path(),
+            &Some(helper.clone()),
+            None,
+            None,
+        )
+        .await;
+
+        match result {
+            Err(CodexErr::Sandbox(SandboxErr::HelperMissing { path })) => {
+                assert_eq!(path, helper);
+            }
+            other => panic!("expected HelperMissing, got {other:?}"),
+        }
+
+        std::fs::write(&helper, "")?;
+        assert!(crate::sandboxing::is_available(
+            SandboxType::LinuxSeccomp,
+            Some(&helper)
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn denied_program_is_rejected_without_running() -> Result<()> {
+        let cwd = tempfile::tempdir()?;
+        let marker = cwd.path().join("marker");
+        let params = ExecParams {
+            cwd: cwd.path().to_path_buf(),
+            timeout_ms: Some(1_000),
+            program_policy: ProgramPolicy {
+                denied_programs: vec!["curl".to_string(), "touch".to_string()],
+                allowed_programs: None,
+            },
+            ..exec_params(vec![
+                "/usr/bin/touch".to_string(),
+                marker.to_string_lossy().to_string(),
+            ])
+        };
+
+        let result = process_exec_tool_call(
+            params,
+            SandboxType::None,
+            &SandboxPolicy::DangerFullAccess,
+            cwd.path(),
+            &None,
+            None,
+            None,
+        )
+        .await;
+
+        match result {
+            Err(CodexErr::Sandbox(SandboxErr::ProgramDenied { program })) => {
+                assert_eq!(program, "touch");
+            }
+            other => panic!("expected ProgramDenied, got {other:?}"),
+        }
+        assert!(!marker.exists());
+        Ok(())
+    }
+
+    #[test]
+    fn allowed_programs_restrict_spawnable_programs() {
+        let policy = ProgramPolicy {
+            denied_programs: vec!["rm".to_string()],
+            allowed_programs: Some(vec!["git".to_string(), "rm".to_string()]),
+        };
+        let check = |program: &str| {
+            ensure_program_allowed(&[program.to_string(), "--help".to_string()], &policy)
+        };
+
+        assert!(check("/usr/bin/git").is_ok());
+        match check("curl") {
+            Err(CodexErr::Sandbox(SandboxErr::ProgramNotAllowed { program })) => {
+                assert_eq!(program, "curl");
+            }
+            other => panic!("expected ProgramNotAllowed, got {other:?}"),
+        }
+        match check("rm") {
+            Err(CodexErr::Sandbox(SandboxErr::ProgramDenied { program })) => {
+                assert_eq!(program, "rm");
+            }
+            other => panic!("expected ProgramDenied, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn allowed_programs_are_checked_inside_shell_scripts() {
+        let policy = ProgramPolicy {
+            denied_programs: Vec::new(),
+            allowed_programs: Some(vec!["bash".to_string(), "git".to_string()]),
+        };
+        let bash_lc = |script: &str| ["bash".to_string(), "-lc".to_string(), script.to_string()];
+
+        assert!(ensure_program_allowed(&bash_lc("git status && git diff"), &policy).is_ok());
+        assert!(ensure_program_allowed(&bash_lc("cd sub && echo ok && git log"), &policy).is_ok());
+        match ensure_program_allowed(&bash_lc("curl x"), &policy) {
+            Err(CodexErr::Sandbox(SandboxErr::ProgramNotAllowed { program })) => {
+                assert_eq!(program, "curl");
+            }
+            other => panic!("expected ProgramNotAllowed, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn denied_programs_are_checked_inside_shell_scripts() {
+        let policy = ProgramPolicy {
+            denied_programs: vec!["curl".to_string()],
+            allowed_programs: None,
+        };
+        let check = |shell: &str, flag: &str, script: &str| {
+            ensure_program_allowed(
+                &[shell.to_string(), flag.to_string(), script.to_string()],
+                &policy,
+            )
+        };
+
+        assert!(check("bash", "-lc", "git status && rg foo | head").is_ok());
+        for (shell, flag, script) in [
+            ("bash", "-lc", "curl -s example.com"),
+            ("/bin/zsh", "-c", "echo hi; (cd /tmp && curl x)"),
+            ("sh", "-c", "echo $(curl x)"),
+        ] {
+            match check(shell, flag, script) {
+                Err(CodexErr::Sandbox(SandboxErr::ProgramDenied { program })) => {
+                    assert_eq!(program, "curl");
+                }
+                other => panic!("expected ProgramDenied for {script:?}, got {other:?}"),
+            }
+        }
+        for script in ["$CMD x", "bash -c 'curl x'", "dash -c 'curl x'"] {
+            match check("bash", "-lc", script) {
+                Err(CodexErr::Sandbox(SandboxErr::UncheckableScript { shell })) => {
+                    assert_eq!(shell, "bash");
+                }
+                other => panic!("expected UncheckableScript for {script:?}, got {other:?}"),
+            }
+        }
+        assert!(
+            ensure_program_allowed(
+                &["bash".to_string(), "-lc".to_string(), "$CMD x".to_string()],
+                &ProgramPolicy::default(),
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn unrecognized_shells_with_scripts_are_refused_under_a_policy() {
+        let policy = ProgramPolicy {
+            denied_programs: vec!["curl".to_string()],
+            allowed_programs: None,
+        };
+
+        for shell in ["dash", "/bin/ksh", "fish", "busybox"] {
+            let command = [shell.to_string(), "-c".to_string(), "curl x".to_string()];
+            match ensure_program_allowed(&command, &policy) {
+                Err(CodexErr::Sandbox(SandboxErr::UncheckableScript { shell: refused })) => {
+                    assert_eq!(refused, program_basename(shell));
+                }
+                other => panic!("expected UncheckableScript for {shell:?}, got {other:?}"),
+            }
+            assert!(ensure_program_allowed(&command, &ProgramPolicy::default()).is_ok());
+        }
+    }
+
+    #[test]
+    fn timeout_duration_applies_default_and_allows_unbounded() {
+        assert_eq!(
+            [None, Some(250), Some(0)].map(timeout_duration),
+            [
+                Duration::from_millis(DEFAULT_TIMEOUT_MS),
+                Duration::from_millis(250),
+                Duration::MAX,
+            ]
+        );
+    }
+
+    #[test]
+    fn plan_exec_reflects_shell_environment_policy() -> Result<()> {
+        let cwd = tempfile::tempdir()?;
+        let env_policy = ShellEnvironmentPolicy {
+            inherit: crate::config::types::ShellEnvironmentPolicyInherit::None,
+            r#set: HashMap::from([
+                ("FOO".to_string(), "bar".to_string()),
+                ("API_TOKEN".to_string(), "hunter2".to_string()),
+                ("DROPPED".to_string(), "1".to_string()),
+            ]),
+            include_only: vec![
+                EnvironmentVariablePattern::new_case_insensitive("FOO"),
+                EnvironmentVariablePattern::new_case_insensitive("API_*"),
+            ],
+            ..Default::default()
+        };
+        let params = ExecParams {
+            cwd: cwd.path().to_path_buf(),
+            timeout_ms: None,
+            env: crate::exec_env::create_env(&env_policy),
+            ..exec_params(vec!["echo".to_string(), "hi".to_string()])
+        };
+
+        let plan = plan_exec(
+            &params,
+            SandboxType::None,
+            &SandboxPolicy::DangerFullAccess,
+            cwd.path(),
+            &None,
+            &env_policy,
+        )?;
+
+        assert_eq!(
+            plan,
+            ExecPlan {
+                command: vec!["echo".to_string(), "hi".to_string()],
+                cwd: cwd.path().to_path_buf(),
+                env: HashMap::from([
+                    ("FOO".to_string(), "bar".to_string()),
+                    ("API_TOKEN".to_string(), "<redacted>".to_string()),
+                ]),
+                sandbox: SandboxType::None,
+                network_access: true,
+                writable_roots: Vec::new(),
+            }
+        );
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn allocate_pty_runs_command_on_a_tty() -> Result<()> {
+        async fn run(allocate_pty: bool) -> Result<ExecToolCallOutput> {
+            let cwd = std::env::current_dir()?;
+            let params = ExecParams {
+                cwd: cwd.clone(),
+                allocate_pty,
+                ..exec_params(vec![
+                    "bash".to_string(),
+                    "-c".to_string(),
+                    "if [ -t 1 ]; then echo tty; else echo pipe; fi".to_string(),
+                ])
+            };
+            process_exec_tool_call(
+                params,
+                SandboxType::None,
+                &SandboxPolicy::DangerFullAccess,
+                cwd.as_path(),
+                &None,
+                None,
+                None,
+            )
+            .await
+        }
+
+        let pty_output = run(true).await?;
+        assert_eq!(pty_output.exit_code, 0);
+        assert_eq!(pty_output.stdout.text.trim(), "tty");
+
+        let pipe_output = run(false).await?;
+        assert_eq!(pipe_output.stdout.text.trim(), "pipe");
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn capture_output_lines_merges_streams_chronologically() -> Result<()> {
+        let cwd = std::env::current_dir()?;
+        let params = ExecParams {
+            cwd: cwd.clone(),
+            capture_output_lines: true,
+            ..exec_params(vec![
+                "bash".to_string(),
+                "-c".to_string(),
+                "echo out1; sleep 0.1; echo err1 >&2; sleep 0.1; echo out2; sleep 0.1; printf err2 >&2"
+                    .to_string(),
+            ])
+        };
+
+        let output = process_exec_tool_call(
+            params,
+            SandboxType::None,
+            &SandboxPolicy::DangerFullAccess,
+            cwd.as_path(),
+            &None,
+            None,
+            None,
+        )
+        .await?;
+
+        let lines: Vec<(ExecOutputStream, String)> = output
+            .output_lines
+            .into_iter()
+            .map(|line| (line.stream, line.text))
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                (ExecOutputStream::Stdout, "out1".to_string()),
+                (ExecOutputStream::Stderr, "err1".to_string()),
+                (ExecOutputStream::Stdout, "out2".to_string()),
+                (ExecOutputStream::Stderr, "err2".to_string()),
+            ]
+        );
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn stop_on_first_stdout_byte_returns_before_command_finishes() -> Result<()> {
+        let cwd = std::env::current_dir()?;
+        let params = ExecParams {
+            cwd: cwd.clone(),
+            timeout_ms: Some(20_000),
+            stop_on_first_stdout_byte: true,
+            ..exec_params(vec![
+                "bash".to_string(),
+                "-c".to_string(),
+                "echo found; sleep 10".to_string(),
+            ])
+        };
+
+        let output = process_exec_tool_call(
+            params,
+            SandboxType::None,
+            &SandboxPolicy::DangerFullAccess,
+            cwd.as_path(),
+            &None,
+            None,
+            None,
+        )
+        .await?;
+
+        assert!(output.stopped_early);
+        assert_eq!(output.exit_code, 0);
+        assert_eq!(output.stdout.text, "found\n");
+        assert!(
+            output.duration < Duration::from_secs(5),
+            "expected early return, took {:?}",
+            output.duration
+        );
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn stdin_is_piped_to_the_command() -> Result<()> {
+        let cwd = std::env::current_dir()?;
+        let params = ExecParams {
+            cwd: cwd.clone(),
+            stdin: Some(b"hello from stdin\n".to_vec()),
+            ..exec_params(vec!["cat".to_string()])
+        };
+
+        let output = process_exec_tool_call(
+            params,
+            SandboxType::None,
+            &SandboxPolicy::DangerFullAccess,
+            cwd.as_path(),
+            &None,
+            None,
+            None,
+        )
+        .await?;
+
+        assert_eq!(output.exit_code, 0);
+        assert_eq!(output.stdout.text, "hello from stdin\n");
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn stdin_left_unread_does_not_fail_the_command() -> Result<()> {
+        let cwd = std::env::current_dir()?;
+        let params = ExecParams {
+            cwd: cwd.clone(),
+            stdin: Some(vec![b'x'; 1 << 20]),
+            ..exec_params(vec!["true".to_string()])
+        };
+
+        let output = process_exec_tool_call(
+            params,
+            SandboxType::None,
+            &SandboxPolicy::DangerFullAccess,
+            cwd.as_path(),
+            &None,
+            None,
+            None,
+        )
+        .await?;
+
+        assert_eq!(output.exit_code, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn missing_program_reports_command_not_found() -> Result<()> {
+        let cwd = std::env::current_dir()?;
+        let program = "codex-test-definitely-missing-binary".to_string();
+        let params = ExecParams {
+            cwd: cwd.clone(),
+            ..exec_params(vec![program.clone()])
+        };
+
+        let result = process_exec_tool_call(
+            params,
+            SandboxType::None,
+            &SandboxPolicy::DangerFullAccess,
+            cwd.as_path(),
+            &None,
+            None,
+            None,
+        )
+        .await;
+
+        match result {
+            Err(CodexErr::CommandNotFound { program: missing }) => assert_eq!(missing, program),
+            other => panic!("expected CommandNotFound, got {other:?}"),
+        }
+        Ok(())
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    #[tokio::test]
+    async fn missing_program_under_sandbox_reports_command_not_found() -> Result<()> {
+        let sandbox_type = if cfg!(target_os = "macos") {
+            SandboxType::MacosSeatbelt
+        } else {
+            SandboxType::LinuxSeccomp
+        };
+        let cwd = std::env::current_dir()?;
+        let program = "codex-test-definitely-missing-binary".to_string();
+        let params = ExecParams {
+            cwd: cwd.clone(),
+            ..exec_params(vec![program.clone()])
+        };
+
+        // The check runs before the sandbox helper is resolved, so no helper
+        // path is needed.
+        let result = process_exec_tool_call(
+            params,
+            sandbox_type,
+            &SandboxPolicy::new_read_only_policy(),
+            cwd.as_path(),
+            &None,
+            None,
+            None,
+        )
+        .await;
+
+        match result {
+            Err(CodexErr::CommandNotFound { program: missing }) => assert_eq!(missing, program),
+            other => panic!("expected CommandNotFound, got {other:?}"),
+        }
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn merge_stderr_into_stdout_keeps_write_order() -> Result<()> {
+        let cwd = std::env::current_dir()?;
+        let params = ExecParams {
+            cwd: cwd.clone(),
+            merge_stderr_into_stdout: true,
+            ..exec_params(vec![
+                "bash".to_string(),
+                "-c".to_string(),
+                "echo out1; echo err1 >&2; echo out2; echo err2 >&2".to_string(),
+            ])
+        };
+
+        let output = process_exec_tool_call(
+            params,
+            SandboxType::None,
+            &SandboxPolicy::DangerFullAccess,
+            cwd.as_path(),
+            &None,
+            None,
+            None,
+        )
+        .await?;
+
+        assert_eq!(output.exit_code, 0);
+        assert_eq!(output.stdout.text, "out1\nerr1\nout2\nerr2\n");
+        assert_eq!(output.stderr.text, "");
+        Ok(())
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn umask_applies_to_files_created_by_the_command() -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir()?;
+        let params = ExecParams {
+            cwd: dir.path().to_path_buf(),
+            umask: Some(0o077),
+            ..exec_params(vec!["touch".to_string(), "created.txt".to_string()])
+        };
+
+        let output = process_exec_tool_call(
+            params,
+            SandboxType::None,
+            &SandboxPolicy::DangerFullAccess,
+            dir.path(),
+            &None,
+            None,
+            None,
+        )
+        .await?;
+
+        assert_eq!(output.exit_code, 0);
+        let mode = std::fs::metadata(dir.path().join("created.txt"))?
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+        Ok(())
+    }
 }
@@ -15,6 +15,39 @@ pub fn create_env(policy: &ShellEnvironmentPolicy) -> HashMap<String, String> {
     populate_env(std::env::vars(), policy)
 }
 
+/// Placeholder that replaces sensitive values in [`redact_env`].
+const REDACTED_VALUE: &str = "<redacted>";
+
+/// Name patterns whose values are always masked by [`redact_env`].
+const DEFAULT_REDACT_PATTERNS: &[&str] = &["*KEY*", "*TOKEN*", "*SECRET*", "*PASSWORD*"];
+
+/// Return a copy of `env` that is safe to render for display: values for
+/// variables whose names match a default sensitive pattern or one of the
+/// policy's `redact` patterns are replaced with a placeholder.
+pub fn redact_env(
+    env: &HashMap<String, String>,
+    policy: &ShellEnvironmentPolicy,
+) -> HashMap<String, String> {
+    let default_patterns: Vec<EnvironmentVariablePattern> = DEFAULT_REDACT_PATTERNS
+        .iter()
+        .map(|pattern| EnvironmentVariablePattern::new_case_insensitive(pattern))
+        .collect();
+    env.iter()
+        .map(|(key, value)| {
+            let sensitive = default_patterns
+                .iter()
+                .chain(&policy.redact)
+                .any(|pattern| pattern.matches(key));
+            let value = if sensitive {
+                REDACTED_VALUE.to_string()
+            } else {
+                value.clone()
+            };
+            (key.clone(), value)
+        })
+        .collect()
+}
+
 fn populate_env<I>(vars: I, policy: &ShellEnvironmentPolicy) -> HashMap<String, String>
 where
     I: IntoIterator<Item = (String, String)>,
@@ -191,4 +224,52 @@ mod tests {
         };
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn redact_env_masks_default_sensitive_keys() {
+        let env: HashMap<String, String> = make_vars(&[
+            ("PATH", "/usr/bin"),
+            ("OPENAI_API_KEY", "sk-123"),
+            ("github_token", "ghp_abc"),
+            ("DB_PASSWORD", "hunter2"),
+            ("CLIENT_SECRET", "s3cr3t"),
+        ])
+        .into_iter()
+        .collect();
+
+        let result = redact_env(&env, &ShellEnvironmentPolicy::default());
+
+        let expected: HashMap<String, String> = hashmap! {
+            "PATH".to_string() => "/usr/bin".to_string(),
+            "OPENAI_API_KEY".to_string() => "<redacted>".to_string(),
+            "github_token".to_string() => "<redacted>".to_string(),
+            "DB_PASSWORD".to_string() => "<redacted>".to_string(),
+            "CLIENT_SECRET".to_string() => "<redacted>".to_string(),
+        };
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn redact_env_applies_policy_patterns_and_keeps_unmatched_keys() {
+        let env: HashMap<String, String> = make_vars(&[
+            ("HOME", "/home/user"),
+            ("DATABASE_URL", "postgres://user:pw@host/db"),
+            ("USER", "user"),
+        ])
+        .into_iter()
+        .collect();
+        let policy = ShellEnvironmentPolicy {
+            redact: vec![EnvironmentVariablePattern::new_case_insensitive("*_URL")],
+            ..Default::default()
+        };
+
+        let result = redact_env(&env, &policy);
+
+        let expected: HashMap<String, String> = hashmap! {
+            "HOME".to_string() => "/home/user".to_string(),
+            "DATABASE_URL".to_string() => "<redacted>".to_string(),
+            "USER".to_string() => "user".to_string(),
+        };
+        assert_eq!(result, expected);
+    }
 }
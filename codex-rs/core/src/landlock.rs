@@ -1,3 +1,4 @@
+use crate::config::types::ShellEnvironmentPolicy;
 use crate::protocol::SandboxPolicy;
 use crate::spawn::StdioPolicy;
 use crate::spawn::spawn_child_async;
@@ -21,6 +22,7 @@ pub async fn spawn_command_under_linux_sandbox<P>(
     sandbox_policy_cwd: &Path,
     stdio_policy: StdioPolicy,
     env: HashMap<String, String>,
+    env_policy: &ShellEnvironmentPolicy,
 ) -> std::io::Result<Child>
 where
     P: AsRef<Path>,
@@ -35,6 +37,8 @@ where
         sandbox_policy,
         stdio_policy,
         env,
+        env_policy,
+        None,
     )
     .await
 }
@@ -580,6 +580,7 @@ pub async fn find_conversation_path_by_id_str(
         cancel,
         compute_indices,
         false,
+        false,
     )
     .map_err(|e| io::Error::other(format!("file search failed: {e}")))?;
 
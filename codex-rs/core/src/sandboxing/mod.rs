@@ -8,6 +8,7 @@ ready‑to‑spawn environment.
 
 pub mod assessment;
 
+use crate::config::types::ShellEnvironmentPolicy;
 use crate::exec::ExecToolCallOutput;
 use crate::exec::SandboxType;
 use crate::exec::StdoutStream;
@@ -25,6 +26,7 @@ use crate::tools::sandboxing::SandboxablePreference;
 use std::collections::HashMap;
 use std::path::Path;
 use std::path::PathBuf;
+use tokio_util::sync::CancellationToken;
 
 #[derive(Clone, Debug)]
 pub struct CommandSpec {
@@ -35,6 +37,12 @@ pub struct CommandSpec {
     pub timeout_ms: Option<u64>,
     pub with_escalated_permissions: Option<bool>,
     pub justification: Option<String>,
+    pub capture_output_lines: bool,
+    pub stop_on_first_stdout_byte: bool,
+    pub stdin: Option<Vec<u8>>,
+    pub merge_stderr_into_stdout: bool,
+    pub umask: Option<u32>,
+    pub env_policy: ShellEnvironmentPolicy,
 }
 
 #[derive(Clone, Debug)]
@@ -47,6 +55,12 @@ pub struct ExecEnv {
     pub with_escalated_permissions: Option<bool>,
     pub justification: Option<String>,
     pub arg0: Option<String>,
+    pub capture_output_lines: bool,
+    pub stop_on_first_stdout_byte: bool,
+    pub stdin: Option<Vec<u8>>,
+    pub merge_stderr_into_stdout: bool,
+    pub umask: Option<u32>,
+    pub env_policy: ShellEnvironmentPolicy,
 }
 
 pub enum SandboxPreference {
@@ -59,11 +73,29 @@ pub enum SandboxPreference {
 pub(crate) enum SandboxTransformError {
     #[error("missing codex-linux-sandbox executable path")]
     MissingLinuxSandboxExecutable,
+    #[error("codex-linux-sandbox executable not found at {}", .0.display())]
+    LinuxSandboxExecutableNotFound(PathBuf),
     #[cfg(not(target_os = "macos"))]
     #[error("seatbelt sandbox is only available on macOS")]
     SeatbeltUnavailable,
 }
 
+/// Returns whether commands can currently be run under `sandbox_type`.
+///
+/// For [`SandboxType::LinuxSeccomp`] this checks that the
+/// `codex-linux-sandbox` helper exists at `codex_linux_sandbox_exe`.
+pub fn is_available(sandbox_type: SandboxType, codex_linux_sandbox_exe: Option<&Path>) -> bool {
+    match sandbox_type {
+        SandboxType::None => true,
+        SandboxType::MacosSeatbelt => {
+            cfg!(target_os = "macos")
+                && Path::new(crate::seatbelt::MACOS_PATH_TO_SEATBELT_EXECUTABLE).is_file()
+        }
+        SandboxType::LinuxSeccomp => codex_linux_sandbox_exe.is_some_and(Path::is_file),
+        SandboxType::WindowsRestrictedToken => cfg!(target_os = "windows"),
+    }
+}
+
 #[derive(Default)]
 pub struct SandboxManager;
 
@@ -129,6 +161,13 @@ impl SandboxManager {
             SandboxType::LinuxSeccomp => {
                 let exe = codex_linux_sandbox_exe
                     .ok_or(SandboxTransformError::MissingLinuxSandboxExecutable)?;
+                // Probe up front so a missing helper surfaces as a clear error
+                // instead of a spawn failure.
+                if !exe.is_file() {
+                    return Err(SandboxTransformError::LinuxSandboxExecutableNotFound(
+                        exe.clone(),
+                    ));
+                }
                 let mut args =
                     create_linux_sandbox_command_args(command.clone(), policy, sandbox_policy_cwd);
                 let mut full_command = Vec::with_capacity(1 + args.len());
@@ -161,6 +200,12 @@ impl SandboxManager {
             with_escalated_permissions: spec.with_escalated_permissions,
             justification: spec.justification.clone(),
             arg0: arg0_override,
+            capture_output_lines: spec.capture_output_lines,
+            stop_on_first_stdout_byte: spec.stop_on_first_stdout_byte,
+            stdin: spec.stdin.clone(),
+            merge_stderr_into_stdout: spec.merge_stderr_into_stdout,
+            umask: spec.umask,
+            env_policy: spec.env_policy.clone(),
         })
     }
 
@@ -173,6 +218,7 @@ pub async fn execute_env(
     env: &ExecEnv,
     policy: &SandboxPolicy,
     stdout_stream: Option<StdoutStream>,
+    cancellation_token: Option<CancellationToken>,
 ) -> crate::error::Result<ExecToolCallOutput> {
-    execute_exec_env(env.clone(), policy, stdout_stream).await
+    execute_exec_env(env.clone(), policy, stdout_stream, cancellation_token).await
 }
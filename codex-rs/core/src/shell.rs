@@ -214,12 +214,22 @@ mod tests {
                     with_escalated_permissions: None,
                     justification: None,
                     arg0: None,
+                    require_cwd_in_writable_roots: false,
+                    allocate_pty: false,
+                    capture_output_lines: false,
+                    stop_on_first_stdout_byte: false,
+                    stdin: None,
+                    merge_stderr_into_stdout: false,
+                    umask: None,
+                    program_policy: Default::default(),
+                    env_policy: Default::default(),
                 },
                 SandboxType::None,
                 &SandboxPolicy::DangerFullAccess,
                 temp_home.path(),
                 &None,
                 None,
+                None,
             )
             .await
             .unwrap();
@@ -311,12 +321,22 @@ mod macos_tests {
                     with_escalated_permissions: None,
                     justification: None,
                     arg0: None,
+                    require_cwd_in_writable_roots: false,
+                    allocate_pty: false,
+                    capture_output_lines: false,
+                    stop_on_first_stdout_byte: false,
+                    stdin: None,
+                    merge_stderr_into_stdout: false,
+                    umask: None,
+                    program_policy: Default::default(),
+                    env_policy: Default::default(),
                 },
                 SandboxType::None,
                 &SandboxPolicy::DangerFullAccess,
                 temp_home.path(),
                 &None,
                 None,
+                None,
             )
             .await
             .unwrap();
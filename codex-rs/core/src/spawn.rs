@@ -5,6 +5,8 @@ use tokio::process::Child;
 use tokio::process::Command;
 use tracing::trace;
 
+use crate::config::types::ShellEnvironmentPolicy;
+use crate::exec_env::redact_env;
 use crate::protocol::SandboxPolicy;
 
 /// Experimental environment variable that will be set to some non-empty value
@@ -25,6 +27,13 @@ pub const CODEX_SANDBOX_ENV_VAR: &str = "CODEX_SANDBOX";
 #[derive(Debug, Clone, Copy)]
 pub enum StdioPolicy {
     RedirectForShellTool,
+    /// Like `RedirectForShellTool`, optionally with a piped stdin the caller
+    /// writes to and with stderr sent into the stdout pipe so both streams
+    /// keep their relative byte order. Merging is only supported on Unix.
+    RedirectForShellToolWith {
+        pipe_stdin: bool,
+        merge_stderr: bool,
+    },
     Inherit,
 }
 
@@ -43,10 +52,15 @@ pub(crate) async fn spawn_child_async(
     sandbox_policy: &SandboxPolicy,
     stdio_policy: StdioPolicy,
     env: HashMap<String, String>,
+    env_policy: &ShellEnvironmentPolicy,
+    #[cfg_attr(not(unix), allow(unused_variables))] umask: Option<u32>,
 ) -> std::io::Result<Child> {
-    trace!(
-        "spawn_child_async: {program:?} {args:?} {arg0:?} {cwd:?} {sandbox_policy:?} {stdio_policy:?} {env:?}"
-    );
+    if tracing::enabled!(tracing::Level::TRACE) {
+        let redacted_env = redact_env(&env, env_policy);
+        trace!(
+            "spawn_child_async: {program:?} {args:?} {arg0:?} {cwd:?} {sandbox_policy:?} {stdio_policy:?} {redacted_env:?}"
+        );
+    }
 
     let mut cmd = Command::new(&program);
     #[cfg(unix)]
@@ -68,11 +82,30 @@ pub(crate) async fn spawn_child_async(
     unsafe {
         #[cfg(target_os = "linux")]
         let parent_pid = libc::getpid();
+        let merge_stderr = matches!(
+            stdio_policy,
+            StdioPolicy::RedirectForShellToolWith {
+                merge_stderr: true,
+                ..
+            }
+        );
         cmd.pre_exec(move || {
             if libc::setpgid(0, 0) == -1 {
                 return Err(std::io::Error::last_os_error());
             }
 
+            if let Some(mask) = umask {
+                // `mode_t` is narrower than u32 on some platforms (e.g. macOS).
+                #[allow(clippy::unnecessary_cast)]
+                let mask = mask as libc::mode_t;
+                libc::umask(mask);
+            }
+
+            // Point fd 2 at the stdout pipe so both streams share one pipe.
+            if merge_stderr && libc::dup2(1, 2) == -1 {
+                return Err(std::io::Error::last_os_error());
+            }
+
             // This relies on prctl(2), so it only works on Linux.
             #[cfg(target_os = "linux")]
             {
@@ -105,6 +138,23 @@ pub(crate) async fn spawn_child_async(
 
             cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
         }
+        StdioPolicy::RedirectForShellToolWith {
+            pipe_stdin,
+            merge_stderr,
+        } => {
+            cmd.stdin(if pipe_stdin {
+                Stdio::piped()
+            } else {
+                Stdio::null()
+            });
+            cmd.stdout(Stdio::piped());
+            // A merged stderr is replaced by the stdout pipe in `pre_exec`.
+            cmd.stderr(if merge_stderr && cfg!(unix) {
+                Stdio::null()
+            } else {
+                Stdio::piped()
+            });
+        }
         StdioPolicy::Inherit => {
             // Inherit stdin, stdout, and stderr from the parent process.
             cmd.stdin(Stdio::inherit())
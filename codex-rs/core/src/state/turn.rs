@@ -12,6 +12,7 @@ use codex_protocol::models::ResponseInputItem;
 use tokio::sync::oneshot;
 
 use crate::codex::TurnContext;
+use crate::exec::ExecControl;
 use crate::protocol::ReviewDecision;
 use crate::tasks::SessionTask;
 
@@ -68,6 +69,8 @@ impl ActiveTurn {
 pub(crate) struct TurnState {
     pending_approvals: HashMap<String, oneshot::Sender<ReviewDecision>>,
     pending_input: Vec<ResponseInputItem>,
+    /// Pause/resume channels of the running shell commands, keyed by call id.
+    exec_controls: HashMap<String, async_channel::Sender<ExecControl>>,
 }
 
 impl TurnState {
@@ -86,6 +89,22 @@ impl TurnState {
         self.pending_approvals.remove(key)
     }
 
+    pub(crate) fn insert_exec_control(
+        &mut self,
+        call_id: String,
+        tx: async_channel::Sender<ExecControl>,
+    ) {
+        self.exec_controls.insert(call_id, tx);
+    }
+
+    pub(crate) fn remove_exec_control(&mut self, call_id: &str) {
+        self.exec_controls.remove(call_id);
+    }
+
+    pub(crate) fn exec_control(&self, call_id: &str) -> Option<async_channel::Sender<ExecControl>> {
+        self.exec_controls.get(call_id).cloned()
+    }
+
     pub(crate) fn clear_pending(&mut self) {
         self.pending_approvals.clear();
         self.pending_input.clear();
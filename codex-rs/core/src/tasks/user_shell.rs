@@ -111,18 +111,26 @@ impl SessionTask for UserShellCommandTask {
             with_escalated_permissions: None,
             justification: None,
             arg0: None,
+            capture_output_lines: false,
+            stop_on_first_stdout_byte: false,
+            stdin: None,
+            merge_stderr_into_stdout: false,
+            umask: None,
+            env_policy: turn_context.shell_environment_policy.clone(),
         };
 
         let stdout_stream = Some(StdoutStream {
             sub_id: turn_context.sub_id.clone(),
             call_id: call_id.clone(),
             tx_event: session.get_tx_event(),
+            control_rx: session.register_exec_control(&call_id).await,
         });
 
         let sandbox_policy = SandboxPolicy::DangerFullAccess;
-        let exec_result = execute_exec_env(exec_env, &sandbox_policy, stdout_stream)
+        let exec_result = execute_exec_env(exec_env, &sandbox_policy, stdout_stream, None)
             .or_cancel(&cancellation_token)
             .await;
+        session.unregister_exec_control(&call_id).await;
 
         match exec_result {
             Err(CancelErr::Cancelled) => {
@@ -134,6 +142,9 @@ impl SessionTask for UserShellCommandTask {
                     aggregated_output: StreamOutput::new(aborted_message.clone()),
                     duration: Duration::ZERO,
                     timed_out: false,
+                    peak_rss_bytes: None,
+                    output_lines: Vec::new(),
+                    stopped_early: false,
                 };
                 let output_items = [user_shell_command_record_item(&raw_command, &exec_output)];
                 session
@@ -185,6 +196,9 @@ impl SessionTask for UserShellCommandTask {
                     aggregated_output: StreamOutput::new(message.clone()),
                     duration: Duration::ZERO,
                     timed_out: false,
+                    peak_rss_bytes: None,
+                    output_lines: Vec::new(),
+                    stopped_early: false,
                 };
                 session
                     .send_event(
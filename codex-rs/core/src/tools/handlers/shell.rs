@@ -7,6 +7,7 @@ use crate::apply_patch::InternalApplyPatchInvocation;
 use crate::apply_patch::convert_apply_patch_to_protocol;
 use crate::codex::TurnContext;
 use crate::exec::ExecParams;
+use crate::exec::ensure_program_allowed;
 use crate::exec_env::create_env;
 use crate::function_tool::FunctionCallError;
 use crate::tools::context::ToolInvocation;
@@ -30,11 +31,25 @@ impl ShellHandler {
         ExecParams {
             command: params.command,
             cwd: turn_context.resolve_path(params.workdir.clone()),
-            timeout_ms: params.timeout_ms,
+            // Only the user's config may disable the timeout; a `0` from the
+            // model is treated as if it had not set `timeout_ms` at all.
+            timeout_ms: params
+                .timeout_ms
+                .filter(|ms| *ms > 0)
+                .or(turn_context.default_exec_timeout_ms),
             env: create_env(&turn_context.shell_environment_policy),
             with_escalated_permissions: params.with_escalated_permissions,
             justification: params.justification,
             arg0: None,
+            require_cwd_in_writable_roots: false,
+            allocate_pty: false,
+            capture_output_lines: false,
+            stop_on_first_stdout_byte: false,
+            stdin: None,
+            merge_stderr_into_stdout: false,
+            umask: None,
+            program_policy: turn_context.program_policy.clone(),
+            env_policy: turn_context.shell_environment_policy.clone(),
         }
     }
 }
@@ -203,6 +218,13 @@ impl ShellHandler {
             }
         }
 
+        // apply_patch is handled in-process above, so the program policy only
+        // applies to commands that are actually spawned.
+        if let Err(err) = ensure_program_allowed(&exec_params.command, &exec_params.program_policy)
+        {
+            return Err(FunctionCallError::RespondToModel(err.to_string()));
+        }
+
         // Regular shell execution path.
         let emitter = ToolEmitter::shell(
             exec_params.command.clone(),
@@ -240,3 +262,53 @@ impl ShellHandler {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codex::make_session_and_context;
+    use pretty_assertions::assert_eq;
+
+    fn shell_params(timeout_ms: Option<u64>) -> ShellToolCallParams {
+        ShellToolCallParams {
+            command: vec!["true".to_string()],
+            workdir: None,
+            timeout_ms,
+            with_escalated_permissions: None,
+            justification: None,
+        }
+    }
+
+    #[test]
+    fn tool_call_timeout_overrides_config_default() {
+        let (_session, mut turn) = make_session_and_context();
+        turn.default_exec_timeout_ms = Some(60_000);
+
+        let exec_params = ShellHandler::to_exec_params(shell_params(Some(1_000)), &turn);
+
+        assert_eq!(exec_params.timeout_ms, Some(1_000));
+    }
+
+    #[test]
+    fn zero_tool_call_timeout_falls_back_to_config_default() {
+        let (_session, mut turn) = make_session_and_context();
+
+        turn.default_exec_timeout_ms = Some(60_000);
+        let exec_params = ShellHandler::to_exec_params(shell_params(Some(0)), &turn);
+        assert_eq!(exec_params.timeout_ms, Some(60_000));
+
+        turn.default_exec_timeout_ms = None;
+        let exec_params = ShellHandler::to_exec_params(shell_params(Some(0)), &turn);
+        assert_eq!(exec_params.timeout_ms, None);
+    }
+
+    #[test]
+    fn config_zero_disables_timeout() {
+        let (_session, mut turn) = make_session_and_context();
+        turn.default_exec_timeout_ms = Some(0);
+
+        let exec_params = ShellHandler::to_exec_params(shell_params(None), &turn);
+
+        assert_eq!(exec_params.timeout_ms, Some(0));
+    }
+}
@@ -3,6 +3,7 @@ use std::path::PathBuf;
 use async_trait::async_trait;
 use serde::Deserialize;
 
+use crate::exec::ensure_program_allowed;
 use crate::function_tool::FunctionCallError;
 use crate::protocol::EventMsg;
 use crate::protocol::ExecCommandOutputDeltaEvent;
@@ -127,6 +128,12 @@ impl ToolHandler for UnifiedExecHandler {
                     )));
                 }
 
+                let shell_flag = if login { "-lc" } else { "-c" };
+                let command = [shell.clone(), shell_flag.to_string(), cmd.clone()];
+                if let Err(err) = ensure_program_allowed(&command, &context.turn.program_policy) {
+                    return Err(FunctionCallError::RespondToModel(err.to_string()));
+                }
+
                 let workdir = workdir
                     .as_deref()
                     .filter(|value| !value.is_empty())
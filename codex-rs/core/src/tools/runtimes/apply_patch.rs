@@ -72,6 +72,12 @@ impl ApplyPatchRuntime {
             env: HashMap::new(),
             with_escalated_permissions: None,
             justification: None,
+            capture_output_lines: false,
+            stop_on_first_stdout_byte: false,
+            stdin: None,
+            merge_stderr_into_stdout: false,
+            umask: None,
+            env_policy: Default::default(),
         })
     }
 
@@ -80,6 +86,7 @@ impl ApplyPatchRuntime {
             sub_id: ctx.turn.sub_id.clone(),
             call_id: ctx.call_id.clone(),
             tx_event: ctx.session.get_tx_event(),
+            control_rx: None,
         })
     }
 }
@@ -155,7 +162,7 @@ impl ToolRuntime<ApplyPatchRequest, ExecToolCallOutput> for ApplyPatchRuntime {
         let env = attempt
             .env_for(&spec)
             .map_err(|err| ToolError::Codex(err.into()))?;
-        let out = execute_env(&env, attempt.policy, Self::stdout_stream(ctx))
+        let out = execute_env(&env, attempt.policy, Self::stdout_stream(ctx), None)
             .await
             .map_err(ToolError::Codex)?;
         Ok(out)
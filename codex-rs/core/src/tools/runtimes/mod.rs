@@ -4,6 +4,7 @@ Module: runtimes
 Concrete ToolRuntime implementations for specific tools. Each runtime stays
 small and focused and reuses the orchestrator for approvals + sandbox + retry.
 */
+use crate::config::types::ShellEnvironmentPolicy;
 use crate::sandboxing::CommandSpec;
 use crate::tools::sandboxing::ToolError;
 use std::collections::HashMap;
@@ -22,6 +23,7 @@ pub(crate) fn build_command_spec(
     timeout_ms: Option<u64>,
     with_escalated_permissions: Option<bool>,
     justification: Option<String>,
+    env_policy: &ShellEnvironmentPolicy,
 ) -> Result<CommandSpec, ToolError> {
     let (program, args) = command
         .split_first()
@@ -34,5 +36,11 @@ pub(crate) fn build_command_spec(
         timeout_ms,
         with_escalated_permissions,
         justification,
+        capture_output_lines: false,
+        stop_on_first_stdout_byte: false,
+        stdin: None,
+        merge_stderr_into_stdout: false,
+        umask: None,
+        env_policy: env_policy.clone(),
     })
 }
@@ -5,6 +5,7 @@ Executes shell requests under the orchestrator: asks for approval when needed,
 builds a CommandSpec, and runs it under the current SandboxAttempt.
 */
 use crate::command_safety::is_dangerous_command::requires_initial_appoval;
+use crate::exec::ExecControl;
 use crate::exec::ExecToolCallOutput;
 use crate::protocol::SandboxPolicy;
 use crate::sandboxing::execute_env;
@@ -59,11 +60,15 @@ impl ShellRuntime {
         Self
     }
 
-    fn stdout_stream(ctx: &ToolCtx<'_>) -> Option<crate::exec::StdoutStream> {
+    fn stdout_stream(
+        ctx: &ToolCtx<'_>,
+        control_rx: Option<async_channel::Receiver<ExecControl>>,
+    ) -> Option<crate::exec::StdoutStream> {
         Some(crate::exec::StdoutStream {
             sub_id: ctx.turn.sub_id.clone(),
             call_id: ctx.call_id.clone(),
             tx_event: ctx.session.get_tx_event(),
+            control_rx,
         })
     }
 }
@@ -147,13 +152,22 @@ impl ToolRuntime<ShellRequest, ExecToolCallOutput> for ShellRuntime {
             req.timeout_ms,
             req.with_escalated_permissions,
             req.justification.clone(),
+            &ctx.turn.shell_environment_policy,
         )?;
         let env = attempt
             .env_for(&spec)
             .map_err(|err| ToolError::Codex(err.into()))?;
-        let out = execute_env(&env, attempt.policy, Self::stdout_stream(ctx))
-            .await
-            .map_err(ToolError::Codex)?;
-        Ok(out)
+        // Registered per attempt so `Op::PauseExecCommand` reaches the
+        // child that is currently running.
+        let control_rx = ctx.session.register_exec_control(&ctx.call_id).await;
+        let out = execute_env(
+            &env,
+            attempt.policy,
+            Self::stdout_stream(ctx, control_rx),
+            None,
+        )
+        .await;
+        ctx.session.unregister_exec_control(&ctx.call_id).await;
+        out.map_err(ToolError::Codex)
     }
 }
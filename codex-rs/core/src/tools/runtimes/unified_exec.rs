@@ -153,7 +153,7 @@ impl<'a> ToolRuntime<UnifiedExecRequest, UnifiedExecSession> for UnifiedExecRunt
         &mut self,
         req: &UnifiedExecRequest,
         attempt: &SandboxAttempt<'_>,
-        _ctx: &ToolCtx<'_>,
+        ctx: &ToolCtx<'_>,
     ) -> Result<UnifiedExecSession, ToolError> {
         let spec = build_command_spec(
             &req.command,
@@ -162,6 +162,7 @@ impl<'a> ToolRuntime<UnifiedExecRequest, UnifiedExecSession> for UnifiedExecRunt
             None,
             req.with_escalated_permissions,
             req.justification.clone(),
+            &ctx.turn.shell_environment_policy,
         )
         .map_err(|_| ToolError::Rejected("missing command line for PTY".to_string()))?;
         let exec_env = attempt
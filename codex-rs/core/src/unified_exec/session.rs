@@ -162,6 +162,9 @@ impl UnifiedExecSession {
             aggregated_output: StreamOutput::new(aggregated_text.clone()),
             duration: Duration::ZERO,
             timed_out: false,
+            peak_rss_bytes: None,
+            output_lines: Vec::new(),
+            stopped_early: false,
         };
 
         if is_likely_sandbox_denied(self.sandbox_type(), &exec_output) {
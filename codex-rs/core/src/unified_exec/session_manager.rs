@@ -257,6 +257,9 @@ impl UnifiedExecSessionManager {
             aggregated_output: StreamOutput::new(aggregated_output),
             duration,
             timed_out: false,
+            peak_rss_bytes: None,
+            output_lines: Vec::new(),
+            stopped_early: false,
         };
         let event_ctx = ToolEventCtx::new(
             entry.session_ref.as_ref(),
@@ -285,6 +288,9 @@ impl UnifiedExecSessionManager {
             aggregated_output: StreamOutput::new(aggregated_output),
             duration,
             timed_out: false,
+            peak_rss_bytes: None,
+            output_lines: Vec::new(),
+            stopped_early: false,
         };
         let event_ctx = ToolEventCtx::new(
             context.session.as_ref(),
@@ -75,6 +75,9 @@ mod tests {
             aggregated_output: StreamOutput::new("hi".to_string()),
             duration: Duration::from_secs(1),
             timed_out: false,
+            peak_rss_bytes: None,
+            output_lines: Vec::new(),
+            stopped_early: false,
         };
         let item = user_shell_command_record_item("echo hi", &exec_output);
         let ResponseItem::Message { content, .. } = item else {
@@ -98,6 +101,9 @@ mod tests {
             aggregated_output: StreamOutput::new("combined output wins".to_string()),
             duration: Duration::from_millis(120),
             timed_out: false,
+            peak_rss_bytes: None,
+            output_lines: Vec::new(),
+            stopped_early: false,
         };
         let record = format_user_shell_command_record("false", &exec_output);
         assert_eq!(
@@ -37,11 +37,20 @@ async fn run_test_cmd(tmp: TempDir, cmd: Vec<&str>) -> Result<ExecToolCallOutput
         with_escalated_permissions: None,
         justification: None,
         arg0: None,
+        require_cwd_in_writable_roots: false,
+        allocate_pty: false,
+        capture_output_lines: false,
+        stop_on_first_stdout_byte: false,
+        stdin: None,
+        merge_stderr_into_stdout: false,
+        umask: None,
+        program_policy: Default::default(),
+        env_policy: Default::default(),
     };
 
     let policy = SandboxPolicy::new_read_only_policy();
 
-    process_exec_tool_call(params, sandbox_type, &policy, tmp.path(), &None, None).await
+    process_exec_tool_call(params, sandbox_type, &policy, tmp.path(), &None, None, None).await
 }
 
 /// Command succeeds with exit code 0 normally
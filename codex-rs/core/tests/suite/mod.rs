@@ -46,6 +46,7 @@ mod review;
 mod rmcp_client;
 mod rollout_list_find;
 mod seatbelt;
+mod seatbelt_exec;
 mod shell_serialization;
 mod stream_error_allows_next_turn;
 mod stream_no_completed;
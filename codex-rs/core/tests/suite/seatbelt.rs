@@ -7,6 +7,7 @@ use std::collections::HashMap;
 use std::path::Path;
 use std::path::PathBuf;
 
+use codex_core::config::types::ShellEnvironmentPolicy;
 use codex_core::protocol::SandboxPolicy;
 use codex_core::seatbelt::spawn_command_under_seatbelt;
 use codex_core::spawn::CODEX_SANDBOX_ENV_VAR;
@@ -192,6 +193,7 @@ async fn python_getpwuid_works_under_seatbelt() {
         sandbox_cwd.as_path(),
         StdioPolicy::RedirectForShellTool,
         HashMap::new(),
+        &ShellEnvironmentPolicy::default(),
     )
     .await
     .expect("should be able to spawn python under seatbelt");
@@ -244,6 +246,7 @@ async fn java_home_finds_runtime_under_seatbelt() {
         sandbox_cwd.as_path(),
         StdioPolicy::RedirectForShellTool,
         env,
+        &ShellEnvironmentPolicy::default(),
     )
     .await
     .expect("should be able to spawn java_home under seatbelt");
@@ -300,6 +303,7 @@ async fn touch(path: &Path, policy: &SandboxPolicy) -> bool {
         sandbox_cwd.as_path(),
         StdioPolicy::RedirectForShellTool,
         HashMap::new(),
+        &ShellEnvironmentPolicy::default(),
     )
     .await
     .expect("should be able to spawn command under seatbelt");
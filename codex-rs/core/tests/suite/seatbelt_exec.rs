@@ -0,0 +1,260 @@
+#![cfg(target_os = "macos")]
+
+//! Seatbelt counterparts of the Landlock tests in `linux-sandbox`. These run
+//! commands through `process_exec_tool_call` with
+//! `SandboxType::MacosSeatbelt` so the generated Seatbelt profile is exercised
+//! with the same write-denial and network-block expectations as on Linux.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use codex_core::config::types::ShellEnvironmentPolicy;
+use codex_core::error::CodexErr;
+use codex_core::error::Result;
+use codex_core::error::SandboxErr;
+use codex_core::exec::ExecParams;
+use codex_core::exec::ExecToolCallOutput;
+use codex_core::exec::SandboxType;
+use codex_core::exec::process_exec_tool_call;
+use codex_core::exec_env::create_env;
+use codex_core::protocol::SandboxPolicy;
+use codex_core::protocol::SandboxPolicyBuilder;
+use codex_core::spawn::CODEX_SANDBOX_ENV_VAR;
+use tempfile::NamedTempFile;
+
+const SHORT_TIMEOUT_MS: u64 = 1_000;
+const LONG_TIMEOUT_MS: u64 = 5_000;
+const NETWORK_TIMEOUT_MS: u64 = 2_000;
+
+/// Seatbelt cannot be nested, so these tests are skipped when the suite itself
+/// runs under Seatbelt.
+fn running_under_seatbelt() -> bool {
+    if std::env::var(CODEX_SANDBOX_ENV_VAR) == Ok("seatbelt".to_string()) {
+        eprintln!("{CODEX_SANDBOX_ENV_VAR} is set to 'seatbelt', skipping test.");
+        return true;
+    }
+    false
+}
+
+fn create_env_from_core_vars() -> HashMap<String, String> {
+    let policy = ShellEnvironmentPolicy::default();
+    create_env(&policy)
+}
+
+async fn exec_under_seatbelt(
+    cmd: &[&str],
+    sandbox_policy: &SandboxPolicy,
+    timeout_ms: u64,
+) -> Result<ExecToolCallOutput> {
+    exec_under_seatbelt_with_stdin(cmd, sandbox_policy, timeout_ms, None).await
+}
+
+#[expect(clippy::expect_used)]
+async fn exec_under_seatbelt_with_stdin(
+    cmd: &[&str],
+    sandbox_policy: &SandboxPolicy,
+    timeout_ms: u64,
+    stdin: Option<Vec<u8>>,
+) -> Result<ExecToolCallOutput> {
+    let cwd = std::env::current_dir().expect("cwd should exist");
+    let sandbox_cwd = cwd.clone();
+    let params = ExecParams {
+        command: cmd.iter().copied().map(str::to_owned).collect(),
+        cwd,
+        timeout_ms: Some(timeout_ms),
+        env: create_env_from_core_vars(),
+        with_escalated_permissions: None,
+        justification: None,
+        arg0: None,
+        require_cwd_in_writable_roots: false,
+        allocate_pty: false,
+        capture_output_lines: false,
+        stop_on_first_stdout_byte: false,
+        stdin,
+        merge_stderr_into_stdout: false,
+        umask: None,
+        program_policy: Default::default(),
+        env_policy: Default::default(),
+    };
+
+    process_exec_tool_call(
+        params,
+        SandboxType::MacosSeatbelt,
+        sandbox_policy,
+        sandbox_cwd.as_path(),
+        &None,
+        None,
+        None,
+    )
+    .await
+}
+
+/// Runs `cmd` under a workspace-write policy (tmp folders excluded, exactly as
+/// in the Landlock suite) and panics unless it exits successfully.
+#[expect(clippy::print_stdout, clippy::unwrap_used)]
+async fn run_cmd(cmd: &[&str], writable_roots: &[PathBuf], timeout_ms: u64) {
+    let sandbox_policy = writable_roots
+        .iter()
+        .fold(SandboxPolicyBuilder::new(), |builder, root| {
+            builder.writable_root(root.clone())
+        })
+        .build();
+    let res = exec_under_seatbelt(cmd, &sandbox_policy, timeout_ms)
+        .await
+        .unwrap();
+
+    if res.exit_code != 0 {
+        println!("stdout:\n{}", res.stdout.text);
+        println!("stderr:\n{}", res.stderr.text);
+        panic!("exit code: {}", res.exit_code);
+    }
+}
+
+/// Panics if `result` reports a successful run; a denial can surface either as
+/// `SandboxErr::Denied` or as a plain non-zero exit code.
+fn assert_denied(cmd: &[&str], result: Result<ExecToolCallOutput>) {
+    let output = match result {
+        Ok(output) => output,
+        Err(CodexErr::Sandbox(SandboxErr::Denied { output })) => *output,
+        _ => {
+            panic!("expected sandbox denied error, got: {result:?}");
+        }
+    };
+
+    if output.exit_code == 0 {
+        panic!(
+            "Seatbelt sandbox FAILED - {cmd:?} exited 0\nstdout:\n{}\nstderr:\n{}",
+            output.stdout.text, output.stderr.text
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_root_read() {
+    if running_under_seatbelt() {
+        return;
+    }
+    run_cmd(&["ls", "-l", "/bin"], &[], SHORT_TIMEOUT_MS).await;
+}
+
+#[tokio::test]
+async fn test_root_write() {
+    if running_under_seatbelt() {
+        return;
+    }
+    let tmpfile = NamedTempFile::new().unwrap();
+    let tmpfile_path = tmpfile.path().to_string_lossy();
+    let cmd = ["bash", "-lc", &format!("echo blah > {tmpfile_path}")];
+    let result =
+        exec_under_seatbelt(&cmd, &SandboxPolicyBuilder::new().build(), SHORT_TIMEOUT_MS).await;
+
+    assert_denied(&cmd, result);
+    assert_eq!(std::fs::read_to_string(tmpfile.path()).unwrap(), "");
+}
+
+#[tokio::test]
+async fn test_dev_null_write() {
+    if running_under_seatbelt() {
+        return;
+    }
+    run_cmd(
+        &["bash", "-lc", "echo blah > /dev/null"],
+        &[],
+        LONG_TIMEOUT_MS,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_writable_root() {
+    if running_under_seatbelt() {
+        return;
+    }
+    let tmpdir = tempfile::tempdir().unwrap();
+    let file_path = tmpdir.path().join("test");
+    run_cmd(
+        &[
+            "bash",
+            "-lc",
+            &format!("echo blah > {}", file_path.to_string_lossy()),
+        ],
+        &[tmpdir.path().to_path_buf()],
+        LONG_TIMEOUT_MS,
+    )
+    .await;
+    assert_eq!(std::fs::read_to_string(&file_path).unwrap(), "blah\n");
+}
+
+#[tokio::test]
+async fn test_timeout() {
+    if running_under_seatbelt() {
+        return;
+    }
+    let result =
+        exec_under_seatbelt(&["sleep", "2"], &SandboxPolicyBuilder::new().build(), 50).await;
+    assert!(
+        matches!(result, Err(CodexErr::Sandbox(SandboxErr::Timeout { .. }))),
+        "expected timeout, got: {result:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_stdin_reaches_sandboxed_command() {
+    if running_under_seatbelt() {
+        return;
+    }
+    let output = exec_under_seatbelt_with_stdin(
+        &["cat"],
+        &SandboxPolicy::new_read_only_policy(),
+        SHORT_TIMEOUT_MS,
+        Some(b"piped input\n".to_vec()),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(output.exit_code, 0);
+    assert_eq!(output.stdout.text, "piped input\n");
+}
+
+/// Runs `cmd` under the read-only policy and asserts that it does NOT succeed.
+async fn assert_network_blocked(cmd: &[&str]) {
+    if running_under_seatbelt() {
+        return;
+    }
+    let result = exec_under_seatbelt(
+        cmd,
+        &SandboxPolicy::new_read_only_policy(),
+        NETWORK_TIMEOUT_MS,
+    )
+    .await;
+    assert_denied(cmd, result);
+}
+
+#[tokio::test]
+async fn sandbox_blocks_curl() {
+    assert_network_blocked(&["curl", "-I", "http://openai.com"]).await;
+}
+
+#[tokio::test]
+async fn sandbox_blocks_nc() {
+    // Zero-length connection attempt to localhost.
+    assert_network_blocked(&["nc", "-z", "127.0.0.1", "80"]).await;
+}
+
+#[tokio::test]
+async fn sandbox_blocks_ssh() {
+    assert_network_blocked(&[
+        "ssh",
+        "-o",
+        "BatchMode=yes",
+        "-o",
+        "ConnectTimeout=1",
+        "github.com",
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn sandbox_blocks_dev_tcp_redirection() {
+    assert_network_blocked(&["bash", "-c", "echo hi > /dev/tcp/127.0.0.1/80"]).await;
+}
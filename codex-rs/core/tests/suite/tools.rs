@@ -228,6 +228,59 @@ async fn shell_escalated_permissions_rejected_then_ok() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn shell_rejects_denied_program_inside_bash_lc() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let mut builder = test_codex().with_config(|config| {
+        config.program_policy.denied_programs = vec!["touch".to_string()];
+    });
+    let test = builder.build(&server).await?;
+
+    let call_id = "shell-denied-in-script";
+    let marker = test.cwd.path().join("marker");
+    let args = json!({
+        "command": ["bash", "-lc", format!("echo hi && touch {}", marker.display())],
+        "timeout_ms": 1_000,
+    });
+
+    mount_sse_once(
+        &server,
+        sse(vec![
+            ev_response_created("resp-1"),
+            ev_function_call(call_id, "shell", &serde_json::to_string(&args)?),
+            ev_completed("resp-1"),
+        ]),
+    )
+    .await;
+    let second_mock = mount_sse_once(
+        &server,
+        sse(vec![
+            ev_assistant_message("msg-1", "done"),
+            ev_completed("resp-2"),
+        ]),
+    )
+    .await;
+
+    submit_turn(
+        &test,
+        "run the shell command",
+        AskForApproval::Never,
+        SandboxPolicy::DangerFullAccess,
+    )
+    .await?;
+
+    let item = second_mock.single_request().function_call_output(call_id);
+    assert_eq!(
+        item.get("output").and_then(Value::as_str),
+        Some("`touch` is not allowed to run: it is listed in denied_programs"),
+    );
+    assert!(!marker.exists(), "denied program must not run");
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn sandbox_denied_shell_returns_original_output() -> Result<()> {
     skip_if_no_network!(Ok(()));
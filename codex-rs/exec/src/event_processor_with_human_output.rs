@@ -518,6 +518,8 @@ impl EventProcessor for EventProcessorWithHumanOutput {
             | EventMsg::ExecApprovalRequest(_)
             | EventMsg::ApplyPatchApprovalRequest(_)
             | EventMsg::ExecCommandOutputDelta(_)
+            | EventMsg::ExecCommandPaused(_)
+            | EventMsg::ExecCommandResumed(_)
             | EventMsg::GetHistoryEntryResponse(_)
             | EventMsg::McpListToolsResponse(_)
             | EventMsg::ListCustomPromptsResponse(_)
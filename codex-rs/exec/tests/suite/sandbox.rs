@@ -1,4 +1,5 @@
 #![cfg(unix)]
+use codex_core::config::types::ShellEnvironmentPolicy;
 use codex_core::protocol::SandboxPolicy;
 use codex_core::spawn::StdioPolicy;
 use std::collections::HashMap;
@@ -27,6 +28,7 @@ async fn spawn_command_under_sandbox(
         sandbox_cwd,
         stdio_policy,
         env,
+        &ShellEnvironmentPolicy::default(),
     )
     .await
 }
@@ -50,6 +52,7 @@ async fn spawn_command_under_sandbox(
         sandbox_cwd,
         stdio_policy,
         env,
+        &ShellEnvironmentPolicy::default(),
     )
     .await
 }
@@ -32,12 +32,15 @@ pub use cli::Cli;
 ///   guidance from `nucleo_matcher::Pattern::indices`: they are
 ///   unique and sorted in ascending order so that callers can use
 ///   them directly for highlighting.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct FileMatch {
     pub score: u32,
     pub path: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub indices: Option<Vec<u32>>, // Sorted & deduplicated when present
+    /// True when the match is a directory rather than a file.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub is_dir: bool,
 }
 
 #[derive(Debug)]
@@ -107,6 +110,7 @@ pub async fn run_main<T: Reporter>(
         cancel_flag,
         compute_indices,
         true,
+        false,
     )?;
     let match_count = matches.len();
     let matches_truncated = total_match_count > match_count;
@@ -122,7 +126,8 @@ pub async fn run_main<T: Reporter>(
 }
 
 /// The worker threads will periodically check `cancel_flag` to see if they
-/// should stop processing files.
+/// should stop processing files. When `include_dirs` is true, directories are
+/// matched alongside files and flagged via [`FileMatch::is_dir`].
 #[allow(clippy::too_many_arguments)]
 pub fn run(
     pattern_text: &str,
@@ -133,6 +138,7 @@ pub fn run(
     cancel_flag: Arc<AtomicBool>,
     compute_indices: bool,
     respect_gitignore: bool,
+    include_dirs: bool,
 ) -> anyhow::Result<FileSearchResults> {
     let pattern = create_pattern(pattern_text);
     // Create one BestMatchesList per worker thread so that each worker can
@@ -200,7 +206,7 @@ pub fn run(
         let cancel = cancel_flag.clone();
 
         Box::new(move |entry| {
-            if let Some(path) = get_file_path(&entry, search_directory) {
+            if let Some(path) = get_file_path(&entry, search_directory, include_dirs) {
                 best_list.insert(path);
             }
 
@@ -216,16 +222,19 @@ pub fn run(
     fn get_file_path<'a>(
         entry_result: &'a Result<ignore::DirEntry, ignore::Error>,
         search_directory: &std::path::Path,
+        include_dirs: bool,
     ) -> Option<&'a str> {
         let entry = match entry_result {
             Ok(e) => e,
             Err(_) => return None,
         };
-        if entry.file_type().is_some_and(|ft| ft.is_dir()) {
+        if !include_dirs && entry.file_type().is_some_and(|ft| ft.is_dir()) {
             return None;
         }
         let path = entry.path();
         match path.strip_prefix(search_directory) {
+            // The search root itself is never a match.
+            Ok(rel_path) if rel_path.as_os_str().is_empty() => None,
             Ok(rel_path) => rel_path.to_str(),
             Err(_) => None,
         }
@@ -285,10 +294,15 @@ pub fn run(
                 None
             };
 
+            // Only the final (limited) matches are stat'ed, which is cheaper
+            // than threading the entry type through the per-worker heaps.
+            let is_dir = include_dirs && search_directory.join(&path).is_dir();
+
             FileMatch {
                 score,
                 path,
                 indices,
+                is_dir,
             }
         })
         .collect();
@@ -403,6 +417,9 @@ fn create_pattern(pattern: &str) -> Pattern {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use pretty_assertions::assert_eq;
+    use std::fs;
+    use tempfile::TempDir;
 
     #[test]
     fn verify_score_is_none_for_non_match() {
@@ -434,4 +451,96 @@ mod tests {
 
         assert_eq!(matches, expected);
     }
+
+    /// Builds a repo with files hidden by a root `.gitignore`, a nested
+    /// `.gitignore`, and `.git/info/exclude`, plus one visible file.
+    fn ignored_files_fixture() -> anyhow::Result<TempDir> {
+        let dir = tempfile::tempdir()?;
+        let root = dir.path();
+        fs::create_dir_all(root.join(".git/info"))?;
+        fs::create_dir_all(root.join("src/gen"))?;
+        fs::create_dir_all(root.join("target/debug"))?;
+        fs::write(root.join(".gitignore"), "target/\n")?;
+        fs::write(root.join("src/.gitignore"), "gen/\n")?;
+        fs::write(root.join(".git/info/exclude"), "foo_local.txt\n")?;
+        fs::write(root.join("src/foo.rs"), "")?;
+        fs::write(root.join("src/gen/foo_gen.rs"), "")?;
+        fs::write(root.join("target/debug/foo.d"), "")?;
+        fs::write(root.join("foo_local.txt"), "")?;
+        Ok(dir)
+    }
+
+    fn search_paths(dir: &Path, respect_gitignore: bool) -> anyhow::Result<Vec<String>> {
+        let results = run(
+            "foo",
+            NonZero::new(10).unwrap(),
+            dir,
+            Vec::new(),
+            NonZero::<usize>::MIN,
+            Arc::new(AtomicBool::new(false)),
+            false,
+            respect_gitignore,
+            false,
+        )?;
+        let mut paths: Vec<String> = results.matches.into_iter().map(|m| m.path).collect();
+        paths.sort();
+        Ok(paths)
+    }
+
+    #[test]
+    fn respects_nested_gitignore_and_info_exclude() -> anyhow::Result<()> {
+        let dir = ignored_files_fixture()?;
+
+        assert_eq!(search_paths(dir.path(), true)?, vec!["src/foo.rs"]);
+        Ok(())
+    }
+
+    #[test]
+    fn include_dirs_flags_directory_matches() -> anyhow::Result<()> {
+        let dir = ignored_files_fixture()?;
+
+        let results = run(
+            "src",
+            NonZero::new(10).unwrap(),
+            dir.path(),
+            Vec::new(),
+            NonZero::<usize>::MIN,
+            Arc::new(AtomicBool::new(false)),
+            false,
+            true,
+            true,
+        )?;
+        let mut matches: Vec<(String, bool)> = results
+            .matches
+            .into_iter()
+            .map(|m| (m.path, m.is_dir))
+            .collect();
+        matches.sort();
+
+        assert_eq!(
+            matches,
+            vec![
+                ("src".to_string(), true),
+                ("src/.gitignore".to_string(), false),
+                ("src/foo.rs".to_string(), false),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn includes_ignored_files_when_gitignore_disabled() -> anyhow::Result<()> {
+        let dir = ignored_files_fixture()?;
+
+        assert_eq!(
+            search_paths(dir.path(), false)?,
+            vec![
+                "foo_local.txt",
+                "src/foo.rs",
+                "src/gen/foo_gen.rs",
+                "target/debug/foo.d",
+            ]
+        );
+        Ok(())
+    }
 }
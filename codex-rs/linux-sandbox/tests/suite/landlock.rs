@@ -7,6 +7,7 @@ use codex_core::exec::SandboxType;
 use codex_core::exec::process_exec_tool_call;
 use codex_core::exec_env::create_env;
 use codex_core::protocol::SandboxPolicy;
+use codex_core::protocol::SandboxPolicyBuilder;
 use std::collections::HashMap;
 use std::path::PathBuf;
 use tempfile::NamedTempFile;
@@ -45,17 +46,26 @@ async fn run_cmd(cmd: &[&str], writable_roots: &[PathBuf], timeout_ms: u64) {
         with_escalated_permissions: None,
         justification: None,
         arg0: None,
+        require_cwd_in_writable_roots: false,
+        allocate_pty: false,
+        capture_output_lines: false,
+        stop_on_first_stdout_byte: false,
+        stdin: None,
+        merge_stderr_into_stdout: false,
+        umask: None,
+        program_policy: Default::default(),
+        env_policy: Default::default(),
     };
 
-    let sandbox_policy = SandboxPolicy::WorkspaceWrite {
-        writable_roots: writable_roots.to_vec(),
-        network_access: false,
-        // Exclude tmp-related folders from writable roots because we need a
-        // folder that is writable by tests but that we intentionally disallow
-        // writing to in the sandbox.
-        exclude_tmpdir_env_var: true,
-        exclude_slash_tmp: true,
-    };
+    // The builder excludes tmp-related folders from writable roots by default,
+    // which we rely on because we need a folder that is writable by tests but
+    // that we intentionally disallow writing to in the sandbox.
+    let sandbox_policy = writable_roots
+        .iter()
+        .fold(SandboxPolicyBuilder::new(), |builder, root| {
+            builder.writable_root(root.clone())
+        })
+        .build();
     let sandbox_program = env!("CARGO_BIN_EXE_codex-linux-sandbox");
     let codex_linux_sandbox_exe = Some(PathBuf::from(sandbox_program));
     let res = process_exec_tool_call(
@@ -65,6 +75,7 @@ async fn run_cmd(cmd: &[&str], writable_roots: &[PathBuf], timeout_ms: u64) {
         sandbox_cwd.as_path(),
         &codex_linux_sandbox_exe,
         None,
+        None,
     )
     .await
     .unwrap();
@@ -148,6 +159,15 @@ async fn assert_network_blocked(cmd: &[&str]) {
         with_escalated_permissions: None,
         justification: None,
         arg0: None,
+        require_cwd_in_writable_roots: false,
+        allocate_pty: false,
+        capture_output_lines: false,
+        stop_on_first_stdout_byte: false,
+        stdin: None,
+        merge_stderr_into_stdout: false,
+        umask: None,
+        program_policy: Default::default(),
+        env_policy: Default::default(),
     };
 
     let sandbox_policy = SandboxPolicy::new_read_only_policy();
@@ -160,6 +180,7 @@ async fn assert_network_blocked(cmd: &[&str]) {
         sandbox_cwd.as_path(),
         &codex_linux_sandbox_exe,
         None,
+        None,
     )
     .await;
 
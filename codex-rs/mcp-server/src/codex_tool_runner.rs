@@ -273,6 +273,8 @@ async fn run_codex_tool_session_inner(
                     | EventMsg::ListCustomPromptsResponse(_)
                     | EventMsg::ExecCommandBegin(_)
                     | EventMsg::ExecCommandOutputDelta(_)
+                    | EventMsg::ExecCommandPaused(_)
+                    | EventMsg::ExecCommandResumed(_)
                     | EventMsg::ExecCommandEnd(_)
                     | EventMsg::BackgroundEvent(_)
                     | EventMsg::StreamError(_)
@@ -153,6 +153,21 @@ pub enum Op {
         decision: ReviewDecision,
     },
 
+    /// Pause a running shell command. Acknowledged with
+    /// `EventMsg::ExecCommandPaused`; ignored when no command with `call_id`
+    /// is running or pausing is unsupported on this platform.
+    PauseExecCommand {
+        /// The `call_id` from the command's `ExecCommandBegin`.
+        call_id: String,
+    },
+
+    /// Resume a command paused with `Op::PauseExecCommand`. Acknowledged
+    /// with `EventMsg::ExecCommandResumed`.
+    ResumeExecCommand {
+        /// The `call_id` from the command's `ExecCommandBegin`.
+        call_id: String,
+    },
+
     /// Append an entry to the persistent cross-session message history.
     ///
     /// Note the entry is not guaranteed to be logged if the user has
@@ -417,6 +432,192 @@ impl SandboxPolicy {
             }
         }
     }
+
+    /// Returns whether the sandbox would allow writes to `path` when commands
+    /// run from `cwd`. Relative paths are resolved against `cwd` and `..`
+    /// components are collapsed before matching. This applies
+    /// the same writable roots, tmp exclusions, and read-only subpaths (e.g.
+    /// `.git`) that [`Self::get_writable_roots_with_cwd`] computes.
+    pub fn is_path_writable(&self, path: &Path, cwd: &Path) -> bool {
+        match self {
+            SandboxPolicy::DangerFullAccess => true,
+            SandboxPolicy::ReadOnly => false,
+            SandboxPolicy::WorkspaceWrite { .. } => {
+                let path = normalize_lexically(&cwd.join(path));
+                self.get_writable_roots_with_cwd(cwd)
+                    .iter()
+                    .any(|writable_root| writable_root.is_path_writable(&path))
+            }
+        }
+    }
+}
+
+/// Fluent builder for [`SandboxPolicy::WorkspaceWrite`].
+///
+/// The builder starts from the most restrictive workspace-write policy: no
+/// extra writable roots, no network access, and neither `TMPDIR` nor `/tmp`
+/// among the default writable roots.
+#[derive(Debug, Clone)]
+pub struct SandboxPolicyBuilder {
+    writable_roots: Vec<PathBuf>,
+    network_access: bool,
+    exclude_tmpdir_env_var: bool,
+    exclude_slash_tmp: bool,
+    allow_escaping_roots: bool,
+    require_existing_roots: bool,
+}
+
+impl SandboxPolicyBuilder {
+    pub fn new() -> Self {
+        Self {
+            writable_roots: Vec::new(),
+            network_access: false,
+            exclude_tmpdir_env_var: true,
+            exclude_slash_tmp: true,
+            allow_escaping_roots: false,
+            require_existing_roots: false,
+        }
+    }
+
+    /// Adds a folder (beyond cwd) that should be writable from within the
+    /// sandbox. Relative paths are resolved against cwd by
+    /// [`SandboxPolicyBuilder::build_with_cwd`].
+    pub fn writable_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.writable_roots.push(root.into());
+        self
+    }
+
+    /// Allows outbound network access.
+    pub fn allow_network(mut self) -> Self {
+        self.network_access = true;
+        self
+    }
+
+    /// Includes both `TMPDIR` and `/tmp` among the default writable roots.
+    pub fn keep_tmpdir(mut self) -> Self {
+        self.exclude_tmpdir_env_var = false;
+        self.exclude_slash_tmp = false;
+        self
+    }
+
+    /// Chooses whether `TMPDIR` is left out of the default writable roots.
+    pub fn exclude_tmpdir_env_var(mut self, exclude: bool) -> Self {
+        self.exclude_tmpdir_env_var = exclude;
+        self
+    }
+
+    /// Chooses whether `/tmp` is left out of the default writable roots.
+    pub fn exclude_slash_tmp(mut self, exclude: bool) -> Self {
+        self.exclude_slash_tmp = exclude;
+        self
+    }
+
+    /// Permits relative writable roots whose `..` components resolve to a
+    /// location outside of cwd.
+    pub fn allow_escaping_roots(mut self) -> Self {
+        self.allow_escaping_roots = true;
+        self
+    }
+
+    /// Makes [`SandboxPolicyBuilder::build_with_cwd`] fail when a writable
+    /// root does not exist on disk.
+    pub fn require_existing_roots(mut self) -> Self {
+        self.require_existing_roots = true;
+        self
+    }
+
+    pub fn build(self) -> SandboxPolicy {
+        SandboxPolicy::WorkspaceWrite {
+            writable_roots: self.writable_roots,
+            network_access: self.network_access,
+            exclude_tmpdir_env_var: self.exclude_tmpdir_env_var,
+            exclude_slash_tmp: self.exclude_slash_tmp,
+        }
+    }
+
+    /// Like [`SandboxPolicyBuilder::build`], but resolves relative writable
+    /// roots against `cwd` first.
+    ///
+    /// Resolution is purely lexical (symlinks are not followed). A relative
+    /// root that climbs out of `cwd` is rejected unless
+    /// [`SandboxPolicyBuilder::allow_escaping_roots`] was called.
+    pub fn build_with_cwd(mut self, cwd: &Path) -> Result<SandboxPolicy, WritableRootError> {
+        let cwd = normalize_lexically(cwd);
+        let mut resolved = Vec::with_capacity(self.writable_roots.len());
+        for root in std::mem::take(&mut self.writable_roots) {
+            let path = if root.is_absolute() {
+                normalize_lexically(&root)
+            } else {
+                let path = normalize_lexically(&cwd.join(&root));
+                if !self.allow_escaping_roots && !path.starts_with(&cwd) {
+                    return Err(WritableRootError::EscapesCwd {
+                        root,
+                        resolved: path,
+                    });
+                }
+                path
+            };
+            if self.require_existing_roots && !path.exists() {
+                return Err(WritableRootError::DoesNotExist { root: path });
+            }
+            resolved.push(path);
+        }
+        self.writable_roots = resolved;
+        Ok(self.build())
+    }
+}
+
+/// Reasons [`SandboxPolicyBuilder::build_with_cwd`] can reject a writable root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WritableRootError {
+    /// A relative root resolved to a location outside of cwd.
+    EscapesCwd { root: PathBuf, resolved: PathBuf },
+    /// The root does not exist and the builder requires existing roots.
+    DoesNotExist { root: PathBuf },
+}
+
+impl fmt::Display for WritableRootError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WritableRootError::EscapesCwd { root, resolved } => write!(
+                f,
+                "writable root `{}` resolves to `{}`, which is outside the working directory",
+                root.display(),
+                resolved.display()
+            ),
+            WritableRootError::DoesNotExist { root } => {
+                write!(f, "writable root `{}` does not exist", root.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for WritableRootError {}
+
+/// Collapses `.` and `..` components without touching the filesystem.
+/// Symlinks are not resolved.
+pub fn normalize_lexically(path: &Path) -> PathBuf {
+    use std::path::Component;
+
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push(component);
+                }
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+impl Default for SandboxPolicyBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 /// Event Queue Entry - events from agent
@@ -491,6 +692,12 @@ pub enum EventMsg {
     /// Incremental chunk of output from a running command.
     ExecCommandOutputDelta(ExecCommandOutputDeltaEvent),
 
+    /// Notification that a running command was suspended.
+    ExecCommandPaused(ExecCommandPausedEvent),
+
+    /// Notification that a suspended command is running again.
+    ExecCommandResumed(ExecCommandResumedEvent),
+
     ExecCommandEnd(ExecCommandEndEvent),
 
     /// Notification that the agent attached a local image via the view_image tool.
@@ -1267,6 +1474,18 @@ pub struct ExecCommandOutputDeltaEvent {
     pub chunk: Vec<u8>,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema, TS)]
+pub struct ExecCommandPausedEvent {
+    /// Identifier for the ExecCommandBegin that was paused.
+    pub call_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema, TS)]
+pub struct ExecCommandResumedEvent {
+    /// Identifier for the ExecCommandBegin that was resumed.
+    pub call_id: String,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
 pub struct BackgroundEventEvent {
     pub message: String,
@@ -1475,6 +1694,162 @@ mod tests {
     use serde_json::json;
     use tempfile::NamedTempFile;
 
+    #[test]
+    fn is_path_writable_respects_writable_roots_and_git_dir() -> Result<()> {
+        let cwd = tempfile::tempdir()?;
+        let extra_root = tempfile::tempdir()?;
+        std::fs::create_dir(cwd.path().join(".git"))?;
+        let policy = SandboxPolicyBuilder::new()
+            .writable_root(extra_root.path())
+            .build();
+
+        assert!(policy.is_path_writable(&cwd.path().join("src/main.rs"), cwd.path()));
+        assert!(policy.is_path_writable(Path::new("relative.txt"), cwd.path()));
+        assert!(policy.is_path_writable(&extra_root.path().join("out.txt"), cwd.path()));
+        assert!(!policy.is_path_writable(&cwd.path().join(".git/config"), cwd.path()));
+        assert!(!policy.is_path_writable(Path::new("/definitely/not/a/root"), cwd.path()));
+        Ok(())
+    }
+
+    #[test]
+    fn is_path_writable_collapses_parent_components() -> Result<()> {
+        let cwd = tempfile::tempdir()?;
+        std::fs::create_dir(cwd.path().join(".git"))?;
+        let policy = SandboxPolicyBuilder::new().build();
+
+        assert!(!policy.is_path_writable(Path::new("../outside"), cwd.path()));
+        assert!(!policy.is_path_writable(Path::new("sub/../.git/config"), cwd.path()));
+        assert!(policy.is_path_writable(Path::new("sub/../src/lib.rs"), cwd.path()));
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn is_path_writable_honors_slash_tmp_exclusion() -> Result<()> {
+        let cwd = tempfile::tempdir()?;
+        let tmp_file = Path::new("/tmp/codex-sandbox-probe");
+
+        let excluded = SandboxPolicyBuilder::new().build();
+        assert!(!excluded.is_path_writable(tmp_file, cwd.path()));
+
+        let included = SandboxPolicyBuilder::new().keep_tmpdir().build();
+        assert!(included.is_path_writable(tmp_file, cwd.path()));
+
+        assert!(SandboxPolicy::DangerFullAccess.is_path_writable(tmp_file, cwd.path()));
+        assert!(!SandboxPolicy::ReadOnly.is_path_writable(tmp_file, cwd.path()));
+        Ok(())
+    }
+
+    #[test]
+    fn sandbox_policy_builder_defaults_to_restrictive_workspace_write() {
+        assert_eq!(
+            SandboxPolicyBuilder::new().build(),
+            SandboxPolicy::WorkspaceWrite {
+                writable_roots: vec![],
+                network_access: false,
+                exclude_tmpdir_env_var: true,
+                exclude_slash_tmp: true,
+            }
+        );
+    }
+
+    #[test]
+    fn sandbox_policy_builder_matches_literal_form() {
+        let policy = SandboxPolicyBuilder::new()
+            .writable_root("/workspace/a")
+            .writable_root(PathBuf::from("/workspace/b"))
+            .allow_network()
+            .keep_tmpdir()
+            .build();
+
+        assert_eq!(
+            policy,
+            SandboxPolicy::WorkspaceWrite {
+                writable_roots: vec![PathBuf::from("/workspace/a"), PathBuf::from("/workspace/b")],
+                network_access: true,
+                exclude_tmpdir_env_var: false,
+                exclude_slash_tmp: false,
+            }
+        );
+    }
+
+    #[test]
+    fn build_with_cwd_resolves_relative_writable_roots() -> Result<()> {
+        let cwd = tempfile::tempdir()?;
+        std::fs::create_dir(cwd.path().join("out"))?;
+
+        let policy = SandboxPolicyBuilder::new()
+            .writable_root("./build/../out")
+            .writable_root("/workspace/abs")
+            .build_with_cwd(cwd.path())?;
+
+        assert_eq!(
+            policy,
+            SandboxPolicy::WorkspaceWrite {
+                writable_roots: vec![cwd.path().join("out"), PathBuf::from("/workspace/abs")],
+                network_access: false,
+                exclude_tmpdir_env_var: true,
+                exclude_slash_tmp: true,
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn build_with_cwd_rejects_escaping_roots_unless_allowed() -> Result<()> {
+        let cwd = tempfile::tempdir()?;
+        let escaped = cwd
+            .path()
+            .parent()
+            .expect("tempdir has a parent")
+            .join("sibling");
+
+        let err = SandboxPolicyBuilder::new()
+            .writable_root("../sibling")
+            .build_with_cwd(cwd.path())
+            .expect_err("escaping root should be rejected");
+        assert_eq!(
+            err,
+            WritableRootError::EscapesCwd {
+                root: PathBuf::from("../sibling"),
+                resolved: escaped.clone(),
+            }
+        );
+
+        let policy = SandboxPolicyBuilder::new()
+            .writable_root("../sibling")
+            .allow_escaping_roots()
+            .build_with_cwd(cwd.path())?;
+        assert_eq!(
+            policy,
+            SandboxPolicy::WorkspaceWrite {
+                writable_roots: vec![escaped],
+                network_access: false,
+                exclude_tmpdir_env_var: true,
+                exclude_slash_tmp: true,
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn build_with_cwd_requires_existing_roots_in_strict_mode() -> Result<()> {
+        let cwd = tempfile::tempdir()?;
+
+        let err = SandboxPolicyBuilder::new()
+            .writable_root("missing")
+            .require_existing_roots()
+            .build_with_cwd(cwd.path())
+            .expect_err("missing root should be rejected");
+        assert_eq!(
+            err,
+            WritableRootError::DoesNotExist {
+                root: cwd.path().join("missing"),
+            }
+        );
+        Ok(())
+    }
+
     #[test]
     fn item_started_event_from_web_search_emits_begin_event() {
         let event = ItemStartedEvent {
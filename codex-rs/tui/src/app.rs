@@ -150,7 +150,14 @@ impl App {
             }
         };
 
-        let file_search = FileSearchManager::new(config.cwd.clone(), app_event_tx.clone());
+        let file_search = FileSearchManager::new(
+            config.cwd.clone(),
+            config.tui_file_search_include_ignored,
+            app_event_tx.clone(),
+        );
+        if let Some(min_debounce_ms) = config.tui_file_search_min_debounce_ms {
+            file_search.set_min_debounce(Duration::from_millis(min_debounce_ms));
+        }
         #[cfg(not(debug_assertions))]
         let upgrade_version = crate::updates::get_upgrade_version(&config);
 
@@ -347,6 +354,9 @@ impl App {
                 return Ok(false);
             }
             AppEvent::CodexOp(op) => self.chat_widget.submit_op(op),
+            AppEvent::StatusGitSummary(git) => {
+                self.chat_widget.add_status_output(git);
+            }
             AppEvent::DiffResult(text) => {
                 // Clear the in-progress state in the bottom pane
                 self.chat_widget.on_diff_complete();
@@ -368,8 +378,13 @@ impl App {
                     self.file_search.on_user_query(query);
                 }
             }
-            AppEvent::FileSearchResult { query, matches } => {
-                self.chat_widget.apply_file_search_result(query, matches);
+            AppEvent::FileSearchResult {
+                query,
+                matches,
+                total_match_count,
+            } => {
+                self.chat_widget
+                    .apply_file_search_result(query, matches, total_match_count);
             }
             AppEvent::UpdateReasoningEffort(effort) => {
                 self.on_update_reasoning_effort(effort);
@@ -734,7 +749,11 @@ mod tests {
         )));
         let auth_manager =
             AuthManager::from_auth_for_testing(CodexAuth::from_api_key("Test API Key"));
-        let file_search = FileSearchManager::new(config.cwd.clone(), app_event_tx.clone());
+        let file_search = FileSearchManager::new(
+            config.cwd.clone(),
+            config.tui_file_search_include_ignored,
+            app_event_tx.clone(),
+        );
 
         App {
             server,
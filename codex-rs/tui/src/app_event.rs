@@ -8,6 +8,7 @@ use codex_file_search::FileMatch;
 
 use crate::bottom_pane::ApprovalRequest;
 use crate::history_cell::HistoryCell;
+use crate::status::GitSummary;
 
 use codex_core::protocol::AskForApproval;
 use codex_core::protocol::SandboxPolicy;
@@ -39,11 +40,16 @@ pub(crate) enum AppEvent {
     FileSearchResult {
         query: String,
         matches: Vec<FileMatch>,
+        /// How many matches the backend found; may exceed `matches.len()`.
+        total_match_count: usize,
     },
 
     /// Result of computing a `/diff` command.
     DiffResult(String),
 
+    /// Git context gathered for a `/status` command; renders the card.
+    StatusGitSummary(Option<GitSummary>),
+
     InsertHistoryCell(Box<dyn HistoryCell>),
 
     StartCommitAnimation,
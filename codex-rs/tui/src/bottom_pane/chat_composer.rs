@@ -55,6 +55,8 @@ use crate::ui_consts::LIVE_PREFIX_COLS;
 use codex_file_search::FileMatch;
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::ops::Range;
 use std::path::Path;
 use std::path::PathBuf;
 use std::time::Duration;
@@ -112,6 +114,13 @@ pub(crate) struct ChatComposer {
     footer_mode: FooterMode,
     footer_hint_override: Option<Vec<(String, String)>>,
     context_window_percent: Option<i64>,
+    /// Paths inserted from the file search popup, most recent last. Passed to
+    /// new popups so recently used files win ranking ties.
+    recent_file_paths: VecDeque<String>,
+    /// Directory the `@` file search runs in; its matches are relative to it.
+    file_search_dir: Option<PathBuf>,
+    /// Overrides the popup's platform default for case-insensitive dedup.
+    file_search_case_fold: Option<bool>,
 }
 
 /// Popup state – at most one can be visible at any time.
@@ -123,6 +132,9 @@ enum ActivePopup {
 
 const FOOTER_SPACING_HEIGHT: u16 = 0;
 
+/// How many inserted file paths the composer remembers for ranking.
+const MAX_RECENT_FILE_PATHS: usize = 20;
+
 impl ChatComposer {
     pub fn new(
         has_input_focus: bool,
@@ -155,6 +167,9 @@ impl ChatComposer {
             footer_mode: FooterMode::ShortcutSummary,
             footer_hint_override: None,
             context_window_percent: None,
+            recent_file_paths: VecDeque::new(),
+            file_search_dir: None,
+            file_search_case_fold: None,
         };
         // Apply configuration via the setter to keep side-effects centralized.
         this.set_disable_paste_burst(disable_paste_burst);
@@ -333,7 +348,12 @@ impl ChatComposer {
     }
 
     /// Integrate results from an asynchronous file search.
-    pub(crate) fn on_file_search_result(&mut self, query: String, matches: Vec<FileMatch>) {
+    pub(crate) fn on_file_search_result(
+        &mut self,
+        query: String,
+        matches: Vec<FileMatch>,
+        total_match_count: usize,
+    ) {
         // Only apply if user is still editing a token starting with `query`.
         let current_opt = Self::current_at_token(&self.textarea);
         let Some(current_token) = current_opt else {
@@ -345,7 +365,7 @@ impl ChatComposer {
         }
 
         if let ActivePopup::File(popup) = &mut self.active_popup {
-            popup.set_matches(&query, matches);
+            popup.set_matches(&query, matches, total_match_count);
         }
     }
 
@@ -600,7 +620,13 @@ impl ChatComposer {
                 modifiers: KeyModifiers::CONTROL,
                 ..
             } => {
-                popup.move_up();
+                if popup.is_browsing_history() {
+                    if let Some(query) = popup.recall_previous() {
+                        self.replace_current_at_token(&query);
+                    }
+                } else {
+                    popup.move_up();
+                }
                 (InputResult::None, true)
             }
             KeyEvent {
@@ -612,7 +638,38 @@ impl ChatComposer {
                 modifiers: KeyModifiers::CONTROL,
                 ..
             } => {
-                popup.move_down();
+                if popup.is_browsing_history() {
+                    let query = popup.recall_next().unwrap_or_default();
+                    self.replace_current_at_token(&query);
+                } else {
+                    popup.move_down();
+                }
+                (InputResult::None, true)
+            }
+            KeyEvent {
+                code: KeyCode::Left,
+                modifiers: KeyModifiers::SHIFT,
+                ..
+            } => {
+                popup.scroll_left();
+                (InputResult::None, true)
+            }
+            KeyEvent {
+                code: KeyCode::Right,
+                modifiers: KeyModifiers::SHIFT,
+                ..
+            } => {
+                popup.scroll_right();
+                (InputResult::None, true)
+            }
+            // Ctrl-Space marks for multi-select; plain Space keeps typing so
+            // queries like `@foo bar` still work.
+            KeyEvent {
+                code: KeyCode::Char(' '),
+                modifiers: KeyModifiers::CONTROL,
+                ..
+            } if popup.selected_match().is_some() => {
+                popup.toggle_mark();
                 (InputResult::None, true)
             }
             KeyEvent {
@@ -633,6 +690,17 @@ impl ChatComposer {
                 modifiers: KeyModifiers::NONE,
                 ..
             } => {
+                let marked: Vec<String> = popup
+                    .marked_paths()
+                    .into_iter()
+                    .map(str::to_string)
+                    .collect();
+                if !marked.is_empty() {
+                    self.insert_selected_paths(&marked);
+                    self.active_popup = ActivePopup::None;
+                    return (InputResult::None, true);
+                }
+
                 let Some(sel) = popup.selected_match() else {
                     self.active_popup = ActivePopup::None;
                     return (InputResult::None, true);
@@ -643,31 +711,18 @@ impl ChatComposer {
                 let is_image = Self::is_image_path(&sel_path);
                 if is_image {
                     // Determine dimensions; if that fails fall back to normal path insertion.
-                    let path_buf = PathBuf::from(&sel_path);
+                    // Matches are relative to the search root, which need not be
+                    // the process working directory.
+                    let path_buf = self
+                        .file_search_dir
+                        .as_deref()
+                        .and_then(|dir| popup.selected_absolute_path(dir))
+                        .unwrap_or_else(|| PathBuf::from(&sel_path));
                     if let Ok((w, h)) = image::image_dimensions(&path_buf) {
-                        // Remove the current @token (mirror logic from insert_selected_path without inserting text)
-                        // using the flat text and byte-offset cursor API.
-                        let cursor_offset = self.textarea.cursor();
-                        let text = self.textarea.text();
-                        // Clamp to a valid char boundary to avoid panics when slicing.
-                        let safe_cursor = Self::clamp_to_char_boundary(text, cursor_offset);
-                        let before_cursor = &text[..safe_cursor];
-                        let after_cursor = &text[safe_cursor..];
-
-                        // Determine token boundaries in the full text.
-                        let start_idx = before_cursor
-                            .char_indices()
-                            .rfind(|(_, c)| c.is_whitespace())
-                            .map(|(idx, c)| idx + c.len_utf8())
-                            .unwrap_or(0);
-                        let end_rel_idx = after_cursor
-                            .char_indices()
-                            .find(|(_, c)| c.is_whitespace())
-                            .map(|(idx, _)| idx)
-                            .unwrap_or(after_cursor.len());
-                        let end_idx = safe_cursor + end_rel_idx;
-
-                        self.textarea.replace_range(start_idx..end_idx, "");
+                        // Remove the current @token without inserting text.
+                        let range = self.current_token_range();
+                        let start_idx = range.start;
+                        self.textarea.replace_range(range, "");
                         self.textarea.set_cursor(start_idx);
 
                         let format_label = match Path::new(&sel_path)
@@ -679,6 +734,7 @@ impl ChatComposer {
                             Some(ext) if ext == "jpg" || ext == "jpeg" => "JPEG",
                             _ => "IMG",
                         };
+                        self.remember_file_path(&sel_path);
                         self.attach_image(path_buf, w, h, format_label);
                         // Add a trailing space to keep typing fluid.
                         self.textarea.insert_str(" ");
@@ -805,45 +861,79 @@ impl ChatComposer {
         left_at.or(right_at)
     }
 
-    /// Replace the active `@token` (the one under the cursor) with `path`.
-    ///
-    /// The algorithm mirrors `current_at_token` so replacement works no matter
-    /// where the cursor is within the token and regardless of how many
-    /// `@tokens` exist in the line.
-    fn insert_selected_path(&mut self, path: &str) {
-        let cursor_offset = self.textarea.cursor();
+    /// Byte range of the whitespace-delimited token under the cursor, mirroring
+    /// the boundaries `current_at_token` uses.
+    fn current_token_range(&self) -> Range<usize> {
         let text = self.textarea.text();
         // Clamp to a valid char boundary to avoid panics when slicing.
-        let safe_cursor = Self::clamp_to_char_boundary(text, cursor_offset);
+        let safe_cursor = Self::clamp_to_char_boundary(text, self.textarea.cursor());
 
         let before_cursor = &text[..safe_cursor];
         let after_cursor = &text[safe_cursor..];
 
-        // Determine token boundaries.
         let start_idx = before_cursor
             .char_indices()
             .rfind(|(_, c)| c.is_whitespace())
             .map(|(idx, c)| idx + c.len_utf8())
             .unwrap_or(0);
-
         let end_rel_idx = after_cursor
             .char_indices()
             .find(|(_, c)| c.is_whitespace())
             .map(|(idx, _)| idx)
             .unwrap_or(after_cursor.len());
-        let end_idx = safe_cursor + end_rel_idx;
 
-        // If the path contains whitespace, wrap it in double quotes so the
+        start_idx..safe_cursor + end_rel_idx
+    }
+
+    /// Replace the `@token` under the cursor with `@{query}`, leaving the
+    /// cursor at its end so the file search popup follows the new query.
+    fn replace_current_at_token(&mut self, query: &str) {
+        let range = self.current_token_range();
+        let start_idx = range.start;
+        let token = format!("@{query}");
+        self.textarea.replace_range(range, &token);
+        self.textarea.set_cursor(start_idx + token.len());
+    }
+
+    /// Replace the active `@token` (the one under the cursor) with `path`.
+    ///
+    /// The algorithm mirrors `current_at_token` so replacement works no matter
+    /// where the cursor is within the token and regardless of how many
+    /// `@tokens` exist in the line.
+    fn insert_selected_path(&mut self, path: &str) {
+        self.insert_selected_paths(&[path]);
+    }
+
+    /// Replace the current `@token` with `paths` separated by spaces, quoting
+    /// any that contain whitespace.
+    fn insert_selected_paths<S: AsRef<str>>(&mut self, paths: &[S]) {
+        for path in paths {
+            self.remember_file_path(path.as_ref());
+        }
+        let Range {
+            start: start_idx,
+            end: end_idx,
+        } = self.current_token_range();
+        let text = self.textarea.text();
+
+        // If a path contains whitespace, wrap it in double quotes so the
         // local prompt arg parser treats it as a single argument. Avoid adding
         // quotes when the path already contains one to keep behavior simple.
-        let needs_quotes = path.chars().any(char::is_whitespace);
-        let inserted = if needs_quotes && !path.contains('"') {
-            format!("\"{path}\"")
-        } else {
-            path.to_string()
-        };
+        let inserted = paths
+            .iter()
+            .map(|path| {
+                let path = path.as_ref();
+                let needs_quotes = path.chars().any(char::is_whitespace);
+                if needs_quotes && !path.contains('"') {
+                    format!("\"{path}\"")
+                } else {
+                    path.to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
 
-        // Replace the slice `[start_idx, end_idx)` with the chosen path and a trailing space.
+        // Replace the slice `[start_idx, end_idx)` with the chosen paths and a trailing space.
         let mut new_text =
             String::with_capacity(text.len() - (end_idx - start_idx) + inserted.len() + 1);
         new_text.push_str(&text[..start_idx]);
@@ -856,6 +946,14 @@ impl ChatComposer {
         self.textarea.set_cursor(new_cursor);
     }
 
+    fn remember_file_path(&mut self, path: &str) {
+        self.recent_file_paths.retain(|recent| recent != path);
+        if self.recent_file_paths.len() == MAX_RECENT_FILE_PATHS {
+            self.recent_file_paths.pop_front();
+        }
+        self.recent_file_paths.push_back(path.to_string());
+    }
+
     /// Handle key event when no popup is visible.
     fn handle_key_event_without_popup(&mut self, key_event: KeyEvent) -> (InputResult, bool) {
         if self.handle_shortcut_overlay_key(&key_event) {
@@ -1461,6 +1559,21 @@ impl ChatComposer {
         }
     }
 
+    /// Set the directory `@` file-search matches are relative to, so attached
+    /// images resolve against it.
+    pub(crate) fn set_file_search_dir(&mut self, dir: PathBuf) {
+        self.file_search_dir = Some(dir);
+    }
+
+    /// Override whether new file-search popups collapse matches that differ
+    /// only in case.
+    pub(crate) fn set_file_search_case_fold(&mut self, case_fold: bool) {
+        self.file_search_case_fold = Some(case_fold);
+        if let ActivePopup::File(popup) = &mut self.active_popup {
+            popup.set_case_fold_dedup(case_fold);
+        }
+    }
+
     pub(crate) fn set_custom_prompts(&mut self, prompts: Vec<CustomPrompt>) {
         self.custom_prompts = prompts.clone();
         if let ActivePopup::Command(popup) = &mut self.active_popup {
@@ -1501,6 +1614,10 @@ impl ChatComposer {
             }
             _ => {
                 let mut popup = FileSearchPopup::new();
+                popup.set_recent_paths(self.recent_file_paths.iter().cloned());
+                if let Some(case_fold) = self.file_search_case_fold {
+                    popup.set_case_fold_dedup(case_fold);
+                }
                 if query.is_empty() {
                     popup.set_empty_prompt();
                 } else {
@@ -2519,6 +2636,168 @@ mod tests {
         assert_eq!(composer.textarea.text(), "@");
     }
 
+    #[test]
+    fn recalled_file_query_moves_selection_once_matches_arrive() {
+        use crossterm::event::KeyCode;
+        use crossterm::event::KeyEvent;
+        use crossterm::event::KeyModifiers;
+
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+        let file_match = |path: &str| FileMatch {
+            score: 0,
+            path: path.to_string(),
+            indices: None,
+            is_dir: false,
+        };
+
+        composer.insert_str("@alpha");
+        composer.on_file_search_result("alpha".to_string(), vec![file_match("alpha.rs")], 0);
+        for _ in 0.."alpha".len() {
+            composer.handle_key_event(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE));
+        }
+        assert_eq!(composer.textarea.text(), "@");
+
+        composer.handle_key_event(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE));
+        assert_eq!(composer.textarea.text(), "@alpha");
+
+        composer.on_file_search_result(
+            "alpha".to_string(),
+            vec![file_match("alpha.rs"), file_match("alpha_test.rs")],
+            0,
+        );
+        composer.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+
+        assert_eq!(composer.textarea.text(), "@alpha");
+        let ActivePopup::File(popup) = &composer.active_popup else {
+            panic!("expected file search popup");
+        };
+        assert_eq!(popup.selected_match(), Some("alpha_test.rs"));
+    }
+
+    #[test]
+    fn space_in_file_popup_inserts_text_and_ctrl_space_marks() {
+        use crossterm::event::KeyCode;
+        use crossterm::event::KeyEvent;
+        use crossterm::event::KeyModifiers;
+
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+        let matches = vec![FileMatch {
+            score: 0,
+            path: "foo.rs".to_string(),
+            indices: None,
+            is_dir: false,
+        }];
+
+        composer.insert_str("@foo");
+        composer.on_file_search_result("foo".to_string(), matches, 0);
+        composer.handle_key_event(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::CONTROL));
+        let ActivePopup::File(popup) = &composer.active_popup else {
+            panic!("expected file search popup");
+        };
+        assert_eq!(popup.marked_paths(), vec!["foo.rs"]);
+        assert_eq!(composer.textarea.text(), "@foo");
+
+        type_chars_humanlike(&mut composer, &[' ']);
+        assert_eq!(composer.textarea.text(), "@foo ");
+    }
+
+    #[test]
+    fn inserted_file_paths_rank_first_in_later_searches() {
+        use crossterm::event::KeyCode;
+        use crossterm::event::KeyEvent;
+        use crossterm::event::KeyModifiers;
+
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+        let matches = || {
+            ["src/main.rs", "tests/main.rs"]
+                .into_iter()
+                .map(|path| FileMatch {
+                    score: 10,
+                    path: path.to_string(),
+                    indices: None,
+                    is_dir: false,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        composer.insert_str("@main");
+        composer.on_file_search_result("main".to_string(), matches(), 0);
+        composer.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+        composer.handle_key_event(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE));
+        assert_eq!(composer.textarea.text(), "tests/main.rs ");
+
+        composer.insert_str("@main");
+        composer.on_file_search_result("main".to_string(), matches(), 0);
+        let ActivePopup::File(popup) = &composer.active_popup else {
+            panic!("expected file search popup");
+        };
+        assert_eq!(popup.selected_match(), Some("tests/main.rs"));
+    }
+
+    #[test]
+    fn selected_image_resolves_against_file_search_dir() {
+        use crossterm::event::KeyCode;
+        use crossterm::event::KeyEvent;
+        use crossterm::event::KeyModifiers;
+
+        let tmp = tempdir().expect("create TempDir");
+        let image_path = tmp.path().join("assets").join("shot.png");
+        std::fs::create_dir_all(image_path.parent().expect("parent")).expect("create assets");
+        let img: ImageBuffer<Rgba<u8>, Vec<u8>> =
+            ImageBuffer::from_fn(3, 2, |_x, _y| Rgba([1, 2, 3, 255]));
+        img.save(&image_path).expect("failed to write temp png");
+
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+        composer.set_file_search_dir(tmp.path().to_path_buf());
+
+        composer.insert_str("@shot");
+        composer.on_file_search_result(
+            "shot".to_string(),
+            vec![FileMatch {
+                score: 10,
+                path: "assets/shot.png".to_string(),
+                indices: None,
+                is_dir: false,
+            }],
+            0,
+        );
+        composer.handle_key_event(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE));
+
+        assert_eq!(composer.take_recent_submission_images(), vec![image_path]);
+    }
+
     #[test]
     fn test_multiple_pastes_submission() {
         use crossterm::event::KeyCode;
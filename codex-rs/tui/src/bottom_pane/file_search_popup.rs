@@ -1,7 +1,16 @@
 use codex_file_search::FileMatch;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+use ratatui::widgets::Widget;
 use ratatui::widgets::WidgetRef;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::path::Path;
+use std::path::PathBuf;
 
 use crate::render::Insets;
 use crate::render::RectExt;
@@ -11,6 +20,15 @@ use super::scroll_state::ScrollState;
 use super::selection_popup_common::GenericDisplayRow;
 use super::selection_popup_common::render_rows;
 
+/// Characters the selected path shifts per horizontal scroll step.
+const HORIZONTAL_SCROLL_STEP: usize = 4;
+
+/// Prefix drawn in front of rows marked for multi-select.
+const MARKED_ROW_PREFIX: &str = "* ";
+
+/// Number of past queries kept for recall.
+const MAX_QUERY_HISTORY: usize = 20;
+
 /// Visual state for the file-search popup.
 pub(crate) struct FileSearchPopup {
     /// Query corresponding to the `matches` currently shown.
@@ -22,27 +40,88 @@ pub(crate) struct FileSearchPopup {
     waiting: bool,
     /// Cached matches; paths relative to the search dir.
     matches: Vec<FileMatch>,
+    /// Matches the backend found for `display_query`, less the duplicates
+    /// dropped from `matches`. Larger than `matches.len()` when the backend
+    /// capped its results.
+    total_match_count: usize,
     /// Shared selection/scroll state.
     state: ScrollState,
+    /// Maximum number of result rows shown at once.
+    max_rows: usize,
+    /// Characters hidden from the start of the selected path so long paths
+    /// can be read to the end. Reset whenever the selection changes.
+    horizontal_offset: usize,
+    /// Queries that produced results, oldest first, without consecutive
+    /// duplicates.
+    query_history: VecDeque<String>,
+    /// Entry of `query_history` currently recalled, if the user is browsing
+    /// history rather than typing.
+    history_cursor: Option<usize>,
+    /// Treat paths differing only in case as duplicates. On for the
+    /// platforms whose filesystems are usually case-insensitive.
+    case_fold_dedup: bool,
+    /// Indices into `matches` the user marked for multi-select insertion.
+    marked: HashSet<usize>,
+    /// Paths the user recently inserted; they rank first among matches with
+    /// equal backend scores.
+    recent_paths: HashSet<String>,
 }
 
 impl FileSearchPopup {
     pub(crate) fn new() -> Self {
+        Self::with_max_rows(MAX_POPUP_ROWS)
+    }
+
+    /// Create a popup that shows up to `max_rows` results before scrolling.
+    pub(crate) fn with_max_rows(max_rows: usize) -> Self {
         Self {
             display_query: String::new(),
             pending_query: String::new(),
             waiting: true,
             matches: Vec::new(),
-            state: ScrollState::new(),
+            total_match_count: 0,
+            state: ScrollState::with_wrap(),
+            max_rows: max_rows.max(1),
+            horizontal_offset: 0,
+            query_history: VecDeque::new(),
+            history_cursor: None,
+            case_fold_dedup: cfg!(any(target_os = "macos", target_os = "windows")),
+            marked: HashSet::new(),
+            recent_paths: HashSet::new(),
         }
     }
 
+    /// Choose whether duplicate detection ignores case. Takes effect on the
+    /// next `set_matches`.
+    pub(crate) fn set_case_fold_dedup(&mut self, case_fold: bool) {
+        self.case_fold_dedup = case_fold;
+    }
+
+    /// Replace the set of recently inserted paths used to break ranking ties.
+    /// Takes effect on the next `set_matches`.
+    pub(crate) fn set_recent_paths<I, S>(&mut self, paths: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.recent_paths = paths.into_iter().map(Into::into).collect();
+    }
+
     /// Update the query and reset state to *waiting*.
     pub(crate) fn set_query(&mut self, query: &str) {
         if query == self.pending_query {
             return;
         }
 
+        // Typing anything other than the recalled query ends history browsing.
+        if self
+            .history_cursor
+            .and_then(|idx| self.query_history.get(idx))
+            .is_some_and(|recalled| recalled != query)
+        {
+            self.history_cursor = None;
+        }
+
         // Determine if current matches are still relevant.
         let keep_existing = query.starts_with(&self.display_query);
 
@@ -53,6 +132,8 @@ impl FileSearchPopup {
 
         if !keep_existing {
             self.matches.clear();
+            self.marked.clear();
+            self.total_match_count = 0;
             self.state.reset();
         }
     }
@@ -64,37 +145,163 @@ impl FileSearchPopup {
         self.pending_query.clear();
         self.waiting = false;
         self.matches.clear();
+        self.marked.clear();
+        self.total_match_count = 0;
+        self.history_cursor = None;
         // Reset selection/scroll state when showing the empty prompt.
         self.state.reset();
     }
 
-    /// Replace matches when a `FileSearchResult` arrives.
     /// Replace matches. Only applied when `query` matches `pending_query`.
-    pub(crate) fn set_matches(&mut self, query: &str, matches: Vec<FileMatch>) {
+    /// `total_match_count` is how many matches the backend found; anything
+    /// up to `matches.len()` means the result set is complete.
+    pub(crate) fn set_matches(
+        &mut self,
+        query: &str,
+        matches: Vec<FileMatch>,
+        total_match_count: usize,
+    ) {
         if query != self.pending_query {
             return; // stale
         }
 
+        let previous_selection = self.selected_match().map(str::to_string);
+        let previously_marked: HashSet<String> = self
+            .marked_paths()
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        let recalled = self.history_cursor.is_some();
+        if !recalled {
+            self.record_query(query);
+        }
+
         self.display_query = query.to_string();
-        self.matches = matches;
+        let returned = matches.len();
+        self.matches = dedup_matches(matches, self.case_fold_dedup);
+        let duplicates = returned - self.matches.len();
+        self.total_match_count = total_match_count
+            .saturating_sub(duplicates)
+            .max(self.matches.len());
+        rank_matches(&mut self.matches, query, &self.recent_paths);
         self.waiting = false;
-        let len = self.matches.len();
-        self.state.clamp_selection(len);
-        self.state.ensure_visible(len, len.min(MAX_POPUP_ROWS));
+        // Once a recalled query has matches, Up/Down go back to moving the
+        // selection; with no matches the user can keep walking history.
+        if recalled && !self.matches.is_empty() {
+            self.history_cursor = None;
+        }
+
+        // Marks follow their paths; marked paths missing from the new results
+        // are dropped.
+        self.marked = self
+            .matches
+            .iter()
+            .enumerate()
+            .filter(|(_, m)| previously_marked.contains(&m.path))
+            .map(|(idx, _)| idx)
+            .collect();
+
+        // Keep the cursor on the same file when it survives the refresh.
+        if let Some(idx) = previous_selection
+            .as_deref()
+            .and_then(|path| self.matches.iter().position(|m| m.path == path))
+        {
+            self.state.selected_idx = Some(idx);
+        }
+        self.state.clamp_selection(self.displayed_count());
+        self.ensure_selection_visible();
+        if self.selected_match() != previous_selection.as_deref() {
+            self.horizontal_offset = 0;
+        }
+    }
+
+    fn record_query(&mut self, query: &str) {
+        if query.is_empty() || self.query_history.back().is_some_and(|last| last == query) {
+            return;
+        }
+        if self.query_history.len() == MAX_QUERY_HISTORY {
+            self.query_history.pop_front();
+        }
+        self.query_history.push_back(query.to_string());
+    }
+
+    /// True when Up/Down should walk query history instead of the match list:
+    /// the query is empty, or is an entry recalled from history whose matches
+    /// have not arrived (or came back empty).
+    pub(crate) fn is_browsing_history(&self) -> bool {
+        self.pending_query.is_empty() || self.history_cursor.is_some()
+    }
+
+    /// Step back to an older query, stopping at the oldest one.
+    pub(crate) fn recall_previous(&mut self) -> Option<String> {
+        let idx = match self.history_cursor {
+            Some(idx) => idx.saturating_sub(1),
+            None => self.query_history.len().checked_sub(1)?,
+        };
+        self.history_cursor = Some(idx);
+        self.query_history.get(idx).cloned()
+    }
+
+    /// Step forward to a newer query. Returns `None` once past the newest
+    /// entry, meaning the query should go back to empty.
+    pub(crate) fn recall_next(&mut self) -> Option<String> {
+        let next = self.history_cursor? + 1;
+        if next < self.query_history.len() {
+            self.history_cursor = Some(next);
+            self.query_history.get(next).cloned()
+        } else {
+            self.history_cursor = None;
+            None
+        }
+    }
+
+    /// Number of matches currently shown.
+    pub(crate) fn displayed_count(&self) -> usize {
+        self.matches.len()
     }
 
     /// Move selection cursor up.
     pub(crate) fn move_up(&mut self) {
         let len = self.matches.len();
-        self.state.move_up_wrap(len);
-        self.state.ensure_visible(len, len.min(MAX_POPUP_ROWS));
+        self.state.move_up(len);
+        self.horizontal_offset = 0;
+        self.ensure_selection_visible();
     }
 
     /// Move selection cursor down.
     pub(crate) fn move_down(&mut self) {
         let len = self.matches.len();
-        self.state.move_down_wrap(len);
-        self.state.ensure_visible(len, len.min(MAX_POPUP_ROWS));
+        self.state.move_down(len);
+        self.horizontal_offset = 0;
+        self.ensure_selection_visible();
+    }
+
+    /// Shift the selected path right to reveal earlier characters.
+    pub(crate) fn scroll_left(&mut self) {
+        self.horizontal_offset = self
+            .horizontal_offset
+            .saturating_sub(HORIZONTAL_SCROLL_STEP);
+    }
+
+    /// Shift the selected path left to reveal its tail.
+    pub(crate) fn scroll_right(&mut self) {
+        let max_offset = self
+            .selected_match()
+            .map_or(0, |path| path.chars().count().saturating_sub(1));
+        self.horizontal_offset = (self.horizontal_offset + HORIZONTAL_SCROLL_STEP).min(max_offset);
+    }
+
+    fn ensure_selection_visible(&mut self) {
+        let len = self.matches.len();
+        self.state.ensure_visible(len, len.min(self.max_rows));
+    }
+
+    /// Position of the selection among the displayed matches, or `None` when
+    /// nothing is selected or the stored index no longer fits the matches.
+    pub(crate) fn selected_index(&self) -> Option<usize> {
+        self.state
+            .selected_idx
+            .filter(|&idx| idx < self.displayed_count())
     }
 
     pub(crate) fn selected_match(&self) -> Option<&str> {
@@ -104,15 +311,114 @@ impl FileSearchPopup {
             .map(|file_match| file_match.path.as_str())
     }
 
+    /// Mark or unmark the selected match for multi-select insertion.
+    pub(crate) fn toggle_mark(&mut self) {
+        let Some(idx) = self.selected_index() else {
+            return;
+        };
+        if !self.marked.remove(&idx) {
+            self.marked.insert(idx);
+        }
+    }
+
+    /// Paths of all marked matches, in display order. Empty when nothing is
+    /// marked, in which case confirming inserts only the selected match.
+    pub(crate) fn marked_paths(&self) -> Vec<&str> {
+        self.matches
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| self.marked.contains(idx))
+            .map(|(_, m)| m.path.as_str())
+            .collect()
+    }
+
+    /// The selected match resolved against `search_dir`, e.g. for copying to
+    /// the clipboard. Matches that are already absolute are returned as-is.
+    pub(crate) fn selected_absolute_path(&self, search_dir: &Path) -> Option<PathBuf> {
+        self.selected_match().map(|path| search_dir.join(path))
+    }
+
     pub(crate) fn calculate_required_height(&self) -> u16 {
         // Row count depends on whether we already have matches. If no matches
         // yet (e.g. initial search or query with no results) reserve a single
         // row so the popup is still visible. When matches are present we show
-        // up to MAX_RESULTS regardless of the waiting flag so the list
+        // up to `max_rows` regardless of the waiting flag so the list
         // remains stable while a newer search is in-flight.
 
-        self.matches.len().clamp(1, MAX_POPUP_ROWS) as u16
+        let rows = self.matches.len().clamp(1, self.max_rows) as u16;
+        if self.shows_truncation_footer() {
+            rows + 1
+        } else {
+            rows
+        }
+    }
+
+    fn shows_truncation_footer(&self) -> bool {
+        self.total_match_count > self.matches.len() && !self.matches.is_empty()
+    }
+}
+
+/// Collapses matches that refer to the same path, keeping the highest score.
+/// With `case_fold`, paths that differ only in case are considered the same.
+fn dedup_matches(matches: Vec<FileMatch>, case_fold: bool) -> Vec<FileMatch> {
+    let mut deduped: Vec<FileMatch> = Vec::with_capacity(matches.len());
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    for file_match in matches {
+        let key = if case_fold {
+            file_match.path.to_lowercase()
+        } else {
+            file_match.path.clone()
+        };
+        match seen.get(&key) {
+            Some(&idx) => {
+                if file_match.score > deduped[idx].score {
+                    deduped[idx] = file_match;
+                }
+            }
+            None => {
+                seen.insert(key, deduped.len());
+                deduped.push(file_match);
+            }
+        }
+    }
+    deduped
+}
+
+/// Stable re-sort that keeps the backend score as the primary key and breaks
+/// ties by preferring recently used paths, then matches whose basename
+/// contains `query`, then shorter paths.
+fn rank_matches(matches: &mut [FileMatch], query: &str, recent_paths: &HashSet<String>) {
+    let query = query.to_lowercase();
+    matches.sort_by_key(|m| {
+        let recent = recent_paths.contains(&m.path);
+        let basename_hit = Path::new(&m.path)
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().to_lowercase().contains(&query));
+        (Reverse(m.score), !recent, !basename_hit, m.path.len())
+    });
+}
+
+/// Drops the first `offset` characters of `path`, marking the cut with a
+/// leading ellipsis and shifting highlight indices to match. Indices of
+/// dropped characters are discarded, so the ellipsis is never highlighted;
+/// the character at `offset` is the first one kept.
+fn scrolled_name(
+    path: &str,
+    match_indices: Option<Vec<usize>>,
+    offset: usize,
+) -> (String, Option<Vec<usize>>) {
+    if offset == 0 {
+        return (path.to_string(), match_indices);
     }
+    let name = format!("…{}", path.chars().skip(offset).collect::<String>());
+    let match_indices = match_indices.map(|indices| {
+        indices
+            .into_iter()
+            .filter(|&i| i >= offset)
+            .map(|i| i - offset + 1)
+            .collect()
+    });
+    (name, match_indices)
 }
 
 impl WidgetRef for &FileSearchPopup {
@@ -123,32 +429,735 @@ impl WidgetRef for &FileSearchPopup {
         } else {
             self.matches
                 .iter()
-                .map(|m| GenericDisplayRow {
-                    name: m.path.clone(),
-                    match_indices: m
+                .enumerate()
+                .map(|(idx, m)| {
+                    let match_indices: Option<Vec<usize>> = m
                         .indices
                         .as_ref()
-                        .map(|v| v.iter().map(|&i| i as usize).collect()),
-                    is_current: false,
-                    display_shortcut: None,
-                    description: None,
+                        .map(|v| v.iter().map(|&i| i as usize).collect());
+                    let offset = if Some(idx) == self.state.selected_idx {
+                        self.horizontal_offset
+                    } else {
+                        0
+                    };
+                    // Directories get a trailing separator so they are
+                    // distinguishable from files before insertion.
+                    let mut display_path = if m.is_dir {
+                        format!("{}{}", m.path, std::path::MAIN_SEPARATOR)
+                    } else {
+                        m.path.clone()
+                    };
+                    // Marked rows get a leading `* `; shift the highlight to
+                    // match.
+                    let match_indices = if self.marked.contains(&idx) {
+                        display_path.insert_str(0, MARKED_ROW_PREFIX);
+                        let shift = MARKED_ROW_PREFIX.chars().count();
+                        match_indices.map(|v| v.into_iter().map(|i| i + shift).collect())
+                    } else {
+                        match_indices
+                    };
+                    let (name, match_indices) = scrolled_name(&display_path, match_indices, offset);
+                    GenericDisplayRow {
+                        name,
+                        match_indices,
+                        is_current: false,
+                        display_shortcut: None,
+                        description: None,
+                    }
                 })
                 .collect()
         };
 
         let empty_message = if self.waiting {
             "loading..."
+        } else if self.display_query.is_empty() {
+            "type to search files"
         } else {
             "no matches"
         };
 
+        let area = area.inset(Insets::tlbr(0, 2, 0, 0));
+        let (rows_area, footer_area) = if self.shows_truncation_footer() && area.height > 1 {
+            let rows_height = area.height - 1;
+            (
+                Rect {
+                    height: rows_height,
+                    ..area
+                },
+                Some(Rect {
+                    y: area.y + rows_height,
+                    height: 1,
+                    ..area
+                }),
+            )
+        } else {
+            (area, None)
+        };
+
         render_rows(
-            area.inset(Insets::tlbr(0, 2, 0, 0)),
+            rows_area,
             buf,
             &rows_all,
             &self.state,
-            MAX_POPUP_ROWS,
+            self.max_rows,
             empty_message,
         );
+
+        if let Some(footer_area) = footer_area {
+            Line::from(
+                format!(
+                    "showing {} of {}",
+                    self.displayed_count(),
+                    self.total_match_count
+                )
+                .dim(),
+            )
+            .render(footer_area, buf);
+        }
+
+        // When the list scrolls, overlay the selected position right-aligned
+        // on the last row instead of spending a row on it.
+        if let Some(idx) = self.selected_index()
+            && self.displayed_count() > self.max_rows
+            && rows_area.height > 0
+        {
+            let indicator_area = Rect {
+                y: rows_area.bottom() - 1,
+                height: 1,
+                ..rows_area
+            };
+            Line::from(format!(" {}/{}", idx + 1, self.displayed_count()).dim())
+                .right_aligned()
+                .render(indicator_area, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn file_match(path: &str) -> FileMatch {
+        FileMatch {
+            score: 0,
+            path: path.to_string(),
+            indices: None,
+            is_dir: false,
+        }
+    }
+
+    fn paths(popup: &FileSearchPopup) -> Vec<&str> {
+        popup.matches.iter().map(|m| m.path.as_str()).collect()
+    }
+
+    fn render_popup(popup: &FileSearchPopup, width: u16) -> Vec<String> {
+        let area = Rect::new(0, 0, width, popup.calculate_required_height());
+        let mut buf = Buffer::empty(area);
+        popup.render_ref(area, &mut buf);
+        (0..area.height)
+            .map(|y| {
+                (0..area.width)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn truncated_results_render_footer() {
+        let mut popup = FileSearchPopup::new();
+        popup.set_query("foo");
+        popup.set_matches(
+            "foo",
+            vec![file_match("foo.rs"), file_match("src/foo.rs")],
+            12,
+        );
+
+        assert_eq!(popup.calculate_required_height(), 3);
+        assert_eq!(
+            render_popup(&popup, 40),
+            vec!["  foo.rs", "  src/foo.rs", "  showing 2 of 12"]
+        );
+    }
+
+    #[test]
+    fn footer_total_excludes_dropped_duplicates() {
+        let mut popup = FileSearchPopup::new();
+        popup.set_case_fold_dedup(false);
+        popup.set_query("foo");
+        popup.set_matches(
+            "foo",
+            vec![
+                file_match("foo.rs"),
+                file_match("foo.rs"),
+                file_match("src/foo.rs"),
+            ],
+            3,
+        );
+
+        assert_eq!(popup.calculate_required_height(), 2);
+        assert_eq!(render_popup(&popup, 40), vec!["  foo.rs", "  src/foo.rs"]);
+    }
+
+    #[test]
+    fn horizontal_scroll_shifts_selected_path_and_resets_on_move() {
+        let mut popup = FileSearchPopup::new();
+        popup.set_query("file");
+        popup.set_matches(
+            "file",
+            vec![
+                file_match("src/deeply/nested/module/file.rs"),
+                file_match("src/deeply/nested/module/other_file.rs"),
+            ],
+            0,
+        );
+
+        popup.scroll_right();
+        popup.scroll_right();
+        assert_eq!(
+            render_popup(&popup, 60),
+            vec![
+                "  …ly/nested/module/file.rs",
+                "  src/deeply/nested/module/other_file.rs",
+            ]
+        );
+
+        popup.scroll_left();
+        assert_eq!(
+            render_popup(&popup, 60)[0],
+            "  …deeply/nested/module/file.rs"
+        );
+
+        popup.move_down();
+        assert_eq!(
+            render_popup(&popup, 60),
+            vec![
+                "  src/deeply/nested/module/file.rs",
+                "  src/deeply/nested/module/other_file.rs",
+            ]
+        );
+    }
+
+    fn search(popup: &mut FileSearchPopup, query: &str) {
+        popup.set_query(query);
+        popup.set_matches(query, vec![file_match(&format!("{query}.rs"))], 0);
+    }
+
+    #[test]
+    fn query_history_cycles_through_recent_queries() {
+        let mut popup = FileSearchPopup::new();
+        for query in ["alpha", "beta", "beta", "gamma"] {
+            search(&mut popup, query);
+        }
+        popup.set_empty_prompt();
+        assert!(popup.is_browsing_history());
+
+        let older: Vec<Option<String>> = (0..4).map(|_| popup.recall_previous()).collect();
+        assert_eq!(
+            older,
+            vec![
+                Some("gamma".to_string()),
+                Some("beta".to_string()),
+                Some("alpha".to_string()),
+                Some("alpha".to_string()),
+            ]
+        );
+
+        let newer: Vec<Option<String>> = (0..3).map(|_| popup.recall_next()).collect();
+        assert_eq!(
+            newer,
+            vec![Some("beta".to_string()), Some("gamma".to_string()), None]
+        );
+    }
+
+    #[test]
+    fn recalled_query_browses_history_until_matches_arrive() {
+        let mut popup = FileSearchPopup::new();
+        search(&mut popup, "alpha");
+        search(&mut popup, "beta");
+        popup.set_empty_prompt();
+
+        let recalled = popup.recall_previous().expect("history entry");
+        popup.set_query(&recalled);
+        assert!(popup.is_browsing_history());
+        assert_eq!(popup.recall_previous(), Some("alpha".to_string()));
+
+        popup.set_query("alpha");
+        popup.set_matches("alpha", vec![], 0);
+        assert!(popup.is_browsing_history());
+
+        popup.set_matches(
+            "alpha",
+            vec![file_match("alpha.rs"), file_match("alpha_test.rs")],
+            0,
+        );
+        assert!(!popup.is_browsing_history());
+        popup.move_down();
+        assert_eq!(popup.selected_match(), Some("alpha_test.rs"));
+    }
+
+    #[test]
+    fn editing_recalled_query_records_it_in_history() {
+        let mut popup = FileSearchPopup::new();
+        search(&mut popup, "alpha");
+        popup.set_empty_prompt();
+
+        let recalled = popup.recall_previous().expect("history entry");
+        popup.set_query(&recalled);
+        search(&mut popup, "alphab");
+        assert!(!popup.is_browsing_history());
+        popup.set_empty_prompt();
+        assert_eq!(popup.recall_previous(), Some("alphab".to_string()));
+    }
+
+    #[test]
+    fn selected_absolute_path_joins_search_dir() {
+        let search_dir = std::env::temp_dir().join("repo");
+        let absolute = std::env::temp_dir().join("elsewhere").join("lib.rs");
+        let mut popup = FileSearchPopup::new();
+        assert_eq!(popup.selected_absolute_path(&search_dir), None);
+
+        popup.set_query("rs");
+        popup.set_matches(
+            "rs",
+            vec![
+                file_match("src/main.rs"),
+                file_match(&absolute.to_string_lossy()),
+            ],
+            0,
+        );
+
+        assert_eq!(
+            popup.selected_absolute_path(&search_dir),
+            Some(search_dir.join("src").join("main.rs"))
+        );
+        popup.move_down();
+        assert_eq!(popup.selected_absolute_path(&search_dir), Some(absolute));
+    }
+
+    fn scored_match(path: &str, score: u32) -> FileMatch {
+        FileMatch {
+            score,
+            ..file_match(path)
+        }
+    }
+
+    #[test]
+    fn case_folded_duplicates_keep_highest_score() {
+        let mut popup = FileSearchPopup::new();
+        popup.set_case_fold_dedup(true);
+        popup.set_query("foo");
+        popup.set_matches(
+            "foo",
+            vec![
+                scored_match("src/Foo.rs", 10),
+                scored_match("src/foo.rs", 20),
+                scored_match("src/FOO.rs", 5),
+            ],
+            0,
+        );
+
+        assert_eq!(popup.matches, vec![scored_match("src/foo.rs", 20)]);
+        assert_eq!(popup.displayed_count(), 1);
+    }
+
+    #[test]
+    fn case_sensitive_dedup_only_collapses_exact_paths() {
+        let mut popup = FileSearchPopup::new();
+        popup.set_case_fold_dedup(false);
+        popup.set_query("foo");
+        popup.set_matches(
+            "foo",
+            vec![
+                scored_match("src/foo.rs", 10),
+                scored_match("src/Foo.rs", 15),
+                scored_match("src/foo.rs", 30),
+            ],
+            0,
+        );
+
+        assert_eq!(
+            popup.matches,
+            vec![
+                scored_match("src/foo.rs", 30),
+                scored_match("src/Foo.rs", 15)
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_query_renders_search_hint() {
+        let mut popup = FileSearchPopup::new();
+        popup.set_empty_prompt();
+
+        assert_eq!(render_popup(&popup, 40), vec!["  type to search files"]);
+    }
+
+    #[test]
+    fn pending_query_renders_loading() {
+        let mut popup = FileSearchPopup::new();
+        popup.set_query("foo");
+
+        assert_eq!(render_popup(&popup, 40), vec!["  loading..."]);
+    }
+
+    #[test]
+    fn query_without_results_renders_no_matches() {
+        let mut popup = FileSearchPopup::new();
+        popup.set_query("foo");
+        popup.set_matches("foo", Vec::new(), 0);
+
+        assert_eq!(render_popup(&popup, 40), vec!["  no matches"]);
+    }
+
+    #[test]
+    fn complete_results_render_without_footer() {
+        let mut popup = FileSearchPopup::new();
+        popup.set_query("foo");
+        popup.set_matches(
+            "foo",
+            vec![file_match("foo.rs"), file_match("src/foo.rs")],
+            0,
+        );
+
+        assert_eq!(popup.calculate_required_height(), 2);
+        assert_eq!(render_popup(&popup, 40), vec!["  foo.rs", "  src/foo.rs"]);
+    }
+
+    #[test]
+    fn equal_scores_prefer_basename_hits_then_shorter_paths() {
+        let mut popup = FileSearchPopup::new();
+        popup.set_query("main");
+        popup.set_matches(
+            "main",
+            vec![
+                FileMatch {
+                    score: 10,
+                    path: "src/a/b/main_helpers.rs".to_string(),
+                    indices: Some(vec![8, 9, 10, 11]),
+                    is_dir: false,
+                },
+                FileMatch {
+                    score: 10,
+                    path: "main/lib.rs".to_string(),
+                    indices: Some(vec![0, 1, 2, 3]),
+                    is_dir: false,
+                },
+                FileMatch {
+                    score: 10,
+                    path: "main.rs".to_string(),
+                    indices: Some(vec![0, 1, 2, 3]),
+                    is_dir: false,
+                },
+                FileMatch {
+                    score: 20,
+                    path: "deep/path/to/domain.rs".to_string(),
+                    indices: Some(vec![15, 16, 17, 18]),
+                    is_dir: false,
+                },
+            ],
+            0,
+        );
+
+        assert_eq!(
+            popup.matches,
+            vec![
+                FileMatch {
+                    score: 20,
+                    path: "deep/path/to/domain.rs".to_string(),
+                    indices: Some(vec![15, 16, 17, 18]),
+                    is_dir: false,
+                },
+                FileMatch {
+                    score: 10,
+                    path: "main.rs".to_string(),
+                    indices: Some(vec![0, 1, 2, 3]),
+                    is_dir: false,
+                },
+                FileMatch {
+                    score: 10,
+                    path: "src/a/b/main_helpers.rs".to_string(),
+                    indices: Some(vec![8, 9, 10, 11]),
+                    is_dir: false,
+                },
+                FileMatch {
+                    score: 10,
+                    path: "main/lib.rs".to_string(),
+                    indices: Some(vec![0, 1, 2, 3]),
+                    is_dir: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn max_rows_controls_scroll_window() {
+        let matches: Vec<FileMatch> = (0..10).map(|i| file_match(&format!("f{i}.rs"))).collect();
+        let mut short = FileSearchPopup::with_max_rows(4);
+        let mut default = FileSearchPopup::new();
+        for popup in [&mut short, &mut default] {
+            popup.set_query("f");
+            popup.set_matches("f", matches.clone(), 0);
+            for _ in 0..5 {
+                popup.move_down();
+            }
+        }
+
+        assert_eq!(short.selected_match(), Some("f5.rs"));
+        assert_eq!(short.state.scroll_top, 2);
+        assert_eq!(short.calculate_required_height(), 4);
+        assert_eq!(default.selected_match(), Some("f5.rs"));
+        assert_eq!(default.state.scroll_top, 0);
+        assert_eq!(default.calculate_required_height(), 8);
+    }
+
+    #[test]
+    fn scrolled_list_renders_position_indicator_on_last_row() {
+        let matches: Vec<FileMatch> = (0..50)
+            .map(|i| file_match(&format!("f{i:02}.rs")))
+            .collect();
+        let mut popup = FileSearchPopup::new();
+        popup.set_query("f");
+        popup.set_matches("f", matches, 0);
+        for _ in 0..24 {
+            popup.move_down();
+        }
+
+        let mut expected: Vec<String> = (17..24).map(|i| format!("  f{i:02}.rs")).collect();
+        expected.push(format!("  f24.rs{}25/50", " ".repeat(27)));
+        assert_eq!(render_popup(&popup, 40), expected);
+    }
+
+    #[test]
+    fn short_list_renders_no_position_indicator() {
+        let mut popup = FileSearchPopup::new();
+        popup.set_query("f");
+        popup.set_matches("f", vec![file_match("f0.rs"), file_match("f1.rs")], 0);
+
+        assert_eq!(render_popup(&popup, 40), vec!["  f0.rs", "  f1.rs"]);
+    }
+
+    #[test]
+    fn wrapping_past_either_end_keeps_selection_visible() {
+        let matches: Vec<FileMatch> = (0..10).map(|i| file_match(&format!("f{i}.rs"))).collect();
+        let mut popup = FileSearchPopup::with_max_rows(4);
+        popup.set_query("f");
+        popup.set_matches("f", matches, 0);
+
+        popup.move_up();
+        assert_eq!(popup.selected_match(), Some("f9.rs"));
+        assert_eq!(popup.state.scroll_top, 6);
+
+        popup.move_down();
+        assert_eq!(popup.selected_match(), Some("f0.rs"));
+        assert_eq!(popup.state.scroll_top, 0);
+    }
+
+    fn scrollable_popup() -> FileSearchPopup {
+        let matches: Vec<FileMatch> = (0..10).map(|i| file_match(&format!("f{i}.rs"))).collect();
+        let mut popup = FileSearchPopup::with_max_rows(4);
+        popup.set_query("f");
+        popup.set_matches("f", matches, 0);
+        popup
+    }
+
+    #[test]
+    fn selected_index_tracks_moves_and_is_none_without_matches() {
+        let mut popup = scrollable_popup();
+        for _ in 0..3 {
+            popup.move_down();
+        }
+        assert_eq!(popup.selected_index(), Some(3));
+        assert_eq!(popup.selected_match(), Some("f3.rs"));
+
+        popup.set_query("zzz");
+        popup.set_matches("zzz", Vec::new(), 0);
+        assert_eq!(popup.selected_index(), None);
+    }
+
+    #[test]
+    fn stale_results_are_ignored() {
+        let mut popup = FileSearchPopup::new();
+        popup.set_query("ma");
+        popup.set_query("mai");
+        popup.set_matches("ma", vec![file_match("ma.rs")], 0);
+        assert_eq!(paths(&popup), Vec::<&str>::new());
+
+        popup.set_matches("mai", vec![file_match("main.rs")], 0);
+        assert_eq!(paths(&popup), vec!["main.rs"]);
+    }
+
+    #[test]
+    fn selection_follows_path_across_refresh() {
+        let mut popup = FileSearchPopup::new();
+        popup.set_query("f");
+        let initial: Vec<FileMatch> = (0..10)
+            .map(|i| file_match(&format!("file{i}.rs")))
+            .collect();
+        popup.set_matches("f", initial, 0);
+        for _ in 0..5 {
+            popup.move_down();
+        }
+        assert_eq!(popup.selected_match(), Some("file5.rs"));
+
+        popup.set_query("fi");
+        let reordered: Vec<FileMatch> = (0..10)
+            .rev()
+            .map(|i| file_match(&format!("file{i}.rs")))
+            .collect();
+        popup.set_matches("fi", reordered, 0);
+
+        assert_eq!(popup.state.selected_idx, Some(4));
+        assert_eq!(popup.selected_match(), Some("file5.rs"));
+    }
+
+    #[test]
+    fn selection_clamps_when_path_disappears() {
+        let mut popup = FileSearchPopup::new();
+        popup.set_query("f");
+        let initial: Vec<FileMatch> = (0..10)
+            .map(|i| file_match(&format!("file{i}.rs")))
+            .collect();
+        popup.set_matches("f", initial, 0);
+        for _ in 0..5 {
+            popup.move_down();
+        }
+
+        popup.set_query("fi");
+        popup.set_matches(
+            "fi",
+            vec![file_match("file1.rs"), file_match("file2.rs")],
+            0,
+        );
+
+        assert_eq!(popup.selected_match(), Some("file2.rs"));
+    }
+
+    #[test]
+    fn directory_matches_render_with_trailing_separator() {
+        let mut popup = FileSearchPopup::new();
+        popup.set_query("src");
+        popup.set_matches(
+            "src",
+            vec![
+                FileMatch {
+                    is_dir: true,
+                    ..file_match("src")
+                },
+                file_match("src.rs"),
+            ],
+            0,
+        );
+
+        let sep = std::path::MAIN_SEPARATOR;
+        assert_eq!(
+            render_popup(&popup, 40),
+            vec![format!("  src{sep}"), "  src.rs".to_string()]
+        );
+    }
+
+    #[test]
+    fn marked_rows_are_returned_in_display_order() {
+        let mut popup = FileSearchPopup::new();
+        popup.set_query("a");
+        popup.set_matches(
+            "a",
+            vec![
+                file_match("a.rs"),
+                file_match("b/a.rs"),
+                file_match("c/a.rs"),
+            ],
+            0,
+        );
+
+        popup.move_down();
+        popup.move_down();
+        popup.toggle_mark();
+        popup.move_up();
+        popup.move_up();
+        popup.toggle_mark();
+
+        assert_eq!(popup.marked_paths(), vec!["a.rs", "c/a.rs"]);
+        assert_eq!(
+            render_popup(&popup, 40),
+            vec!["  * a.rs", "  b/a.rs", "  * c/a.rs"]
+        );
+    }
+
+    #[test]
+    fn toggling_a_marked_row_unmarks_it() {
+        let mut popup = FileSearchPopup::new();
+        popup.set_query("a");
+        popup.set_matches("a", vec![file_match("a.rs"), file_match("b/a.rs")], 0);
+
+        popup.toggle_mark();
+        popup.toggle_mark();
+
+        assert_eq!(popup.marked_paths(), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn marks_follow_paths_across_refresh() {
+        let mut popup = FileSearchPopup::new();
+        popup.set_query("a");
+        popup.set_matches("a", vec![file_match("a.rs"), file_match("b/a.rs")], 0);
+        popup.move_down();
+        popup.toggle_mark();
+
+        popup.set_query("a.");
+        popup.set_matches("a.", vec![file_match("b/a.rs")], 0);
+
+        assert_eq!(popup.marked_paths(), vec!["b/a.rs"]);
+    }
+
+    #[test]
+    fn recent_paths_win_ties_but_not_over_higher_scores() {
+        let mut popup = FileSearchPopup::new();
+        popup.set_recent_paths(["src/a/b/main_helpers.rs", "old/main_unused.rs"]);
+        popup.set_query("main");
+        popup.set_matches(
+            "main",
+            vec![
+                FileMatch {
+                    score: 10,
+                    ..file_match("main.rs")
+                },
+                FileMatch {
+                    score: 10,
+                    ..file_match("src/a/b/main_helpers.rs")
+                },
+                FileMatch {
+                    score: 20,
+                    ..file_match("domain.rs")
+                },
+                FileMatch {
+                    score: 5,
+                    ..file_match("old/main_unused.rs")
+                },
+            ],
+            0,
+        );
+
+        assert_eq!(
+            paths(&popup),
+            vec![
+                "domain.rs",
+                "src/a/b/main_helpers.rs",
+                "main.rs",
+                "old/main_unused.rs"
+            ]
+        );
+    }
+
+    #[test]
+    fn scrolled_name_drops_and_shifts_indices_across_the_cut() {
+        // Indices 3 ('/') and 4 ('d') sit on either side of the cut at 4.
+        assert_eq!(
+            scrolled_name("src/deeply/file.rs", Some(vec![0, 3, 4, 5, 11]), 4),
+            ("…deeply/file.rs".to_string(), Some(vec![1, 2, 8]))
+        );
     }
 }
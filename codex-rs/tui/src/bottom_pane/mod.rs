@@ -345,6 +345,15 @@ impl BottomPane {
         self.request_redraw();
     }
 
+    /// Set the directory `@` file-search matches are relative to.
+    pub(crate) fn set_file_search_dir(&mut self, dir: PathBuf) {
+        self.composer.set_file_search_dir(dir);
+    }
+
+    pub(crate) fn set_file_search_case_fold(&mut self, case_fold: bool) {
+        self.composer.set_file_search_case_fold(case_fold);
+    }
+
     /// Update custom prompts available for the slash popup.
     pub(crate) fn set_custom_prompts(&mut self, prompts: Vec<CustomPrompt>) {
         self.composer.set_custom_prompts(prompts);
@@ -444,8 +453,14 @@ impl BottomPane {
         }
     }
 
-    pub(crate) fn on_file_search_result(&mut self, query: String, matches: Vec<FileMatch>) {
-        self.composer.on_file_search_result(query, matches);
+    pub(crate) fn on_file_search_result(
+        &mut self,
+        query: String,
+        matches: Vec<FileMatch>,
+        total_match_count: usize,
+    ) {
+        self.composer
+            .on_file_search_result(query, matches, total_match_count);
         self.request_redraw();
     }
 
@@ -2,12 +2,15 @@
 ///
 /// Encapsulates the common behavior of a selectable list that supports:
 /// - Optional selection (None when list is empty)
-/// - Wrap-around navigation on Up/Down
+/// - Wrap-around navigation on Up/Down, either always (`*_wrap`) or when
+///   `wrap` is set (`move_up`/`move_down`)
 /// - Maintaining a scroll window (`scroll_top`) so the selected row stays visible
 #[derive(Debug, Default, Clone, Copy)]
 pub(crate) struct ScrollState {
     pub selected_idx: Option<usize>,
     pub scroll_top: usize,
+    /// Whether `move_up`/`move_down` wrap at the ends instead of clamping.
+    pub wrap: bool,
 }
 
 impl ScrollState {
@@ -15,6 +18,15 @@ impl ScrollState {
         Self {
             selected_idx: None,
             scroll_top: 0,
+            wrap: false,
+        }
+    }
+
+    /// Like `new`, with `move_up`/`move_down` wrapping at the ends.
+    pub fn with_wrap() -> Self {
+        Self {
+            wrap: true,
+            ..Self::new()
         }
     }
 
@@ -62,6 +74,30 @@ impl ScrollState {
         });
     }
 
+    /// Move selection up by one. Wraps to the bottom when `wrap` is set,
+    /// otherwise stays on the first item.
+    pub fn move_up(&mut self, len: usize) {
+        if self.wrap {
+            self.move_up_wrap(len);
+        } else if len == 0 {
+            self.reset();
+        } else {
+            self.selected_idx = Some(self.selected_idx.map_or(0, |idx| idx.saturating_sub(1)));
+        }
+    }
+
+    /// Move selection down by one. Wraps to the top when `wrap` is set,
+    /// otherwise stays on the last item.
+    pub fn move_down(&mut self, len: usize) {
+        if self.wrap {
+            self.move_down_wrap(len);
+        } else if len == 0 {
+            self.reset();
+        } else {
+            self.selected_idx = Some(self.selected_idx.map_or(0, |idx| (idx + 1).min(len - 1)));
+        }
+    }
+
     /// Adjust `scroll_top` so that the current `selected_idx` is visible within
     /// the window of `visible_rows`.
     pub fn ensure_visible(&mut self, len: usize, visible_rows: usize) {
@@ -87,6 +123,7 @@ impl ScrollState {
 #[cfg(test)]
 mod tests {
     use super::ScrollState;
+    use pretty_assertions::assert_eq;
 
     #[test]
     fn wrap_navigation_and_visibility() {
@@ -112,4 +149,34 @@ mod tests {
         assert_eq!(s.selected_idx, Some(0));
         assert_eq!(s.scroll_top, 0);
     }
+
+    #[test]
+    fn move_clamps_at_both_ends_without_wrap() {
+        let mut s = ScrollState::new();
+        let len = 3;
+
+        s.clamp_selection(len);
+        s.move_up(len);
+        assert_eq!(s.selected_idx, Some(0));
+
+        s.selected_idx = Some(len - 1);
+        s.move_down(len);
+        assert_eq!(s.selected_idx, Some(len - 1));
+    }
+
+    #[test]
+    fn move_wraps_at_both_ends_with_wrap() {
+        let mut s = ScrollState::with_wrap();
+        let len = 10;
+        let vis = 5;
+
+        s.clamp_selection(len);
+        s.move_up(len);
+        s.ensure_visible(len, vis);
+        assert_eq!((s.selected_idx, s.scroll_top), (Some(len - 1), len - vis));
+
+        s.move_down(len);
+        s.ensure_visible(len, vis);
+        assert_eq!((s.selected_idx, s.scroll_top), (Some(0), 0));
+    }
 }
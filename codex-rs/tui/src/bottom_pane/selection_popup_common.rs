@@ -47,6 +47,23 @@ fn compute_desc_col(
     desc_col
 }
 
+/// Coalesce highlight indices into `(start, len)` runs of consecutive
+/// positions. Input order and duplicates do not matter.
+pub(crate) fn coalesce_match_runs(indices: &[usize]) -> Vec<(usize, usize)> {
+    let mut sorted = indices.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+
+    let mut runs: Vec<(usize, usize)> = Vec::new();
+    for idx in sorted {
+        match runs.last_mut() {
+            Some((start, len)) if *start + *len == idx => *len += 1,
+            _ => runs.push((idx, 1)),
+        }
+    }
+    runs
+}
+
 /// Build the full display line for a row with the description padded to start
 /// at `desc_col`. Applies fuzzy-match bolding when indices are present and
 /// dims the description.
@@ -60,7 +77,11 @@ fn build_full_line(row: &GenericDisplayRow, desc_col: usize) -> Line<'static> {
     let mut truncated = false;
 
     if let Some(idxs) = row.match_indices.as_ref() {
-        let mut idx_iter = idxs.iter().peekable();
+        // Emit one span per run of equally-styled characters instead of one
+        // span per character.
+        let mut runs = coalesce_match_runs(idxs).into_iter().peekable();
+        let mut pending = String::new();
+        let mut pending_bold = false;
         for (char_idx, ch) in row.name.chars().enumerate() {
             let ch_w = UnicodeWidthChar::width(ch).unwrap_or(0);
             if used_width + ch_w > name_limit {
@@ -69,12 +90,30 @@ fn build_full_line(row: &GenericDisplayRow, desc_col: usize) -> Line<'static> {
             }
             used_width += ch_w;
 
-            if idx_iter.peek().is_some_and(|next| **next == char_idx) {
-                idx_iter.next();
-                name_spans.push(ch.to_string().bold());
-            } else {
-                name_spans.push(ch.to_string().into());
+            while runs
+                .peek()
+                .is_some_and(|&(start, len)| start + len <= char_idx)
+            {
+                runs.next();
+            }
+            let bold = runs.peek().is_some_and(|&(start, _)| start <= char_idx);
+            if bold != pending_bold && !pending.is_empty() {
+                let text = std::mem::take(&mut pending);
+                name_spans.push(if pending_bold {
+                    text.bold()
+                } else {
+                    text.into()
+                });
             }
+            pending_bold = bold;
+            pending.push(ch);
+        }
+        if !pending.is_empty() {
+            name_spans.push(if pending_bold {
+                pending.bold()
+            } else {
+                pending.into()
+            });
         }
     } else {
         for ch in row.name.chars() {
@@ -263,3 +302,64 @@ pub(crate) fn measure_rows_height(
     }
     total.max(1)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn coalesce_match_runs_merges_adjacent_indices() {
+        assert_eq!(coalesce_match_runs(&[0, 1, 2, 5, 6]), vec![(0, 3), (5, 2)]);
+    }
+
+    #[test]
+    fn coalesce_match_runs_tolerates_unsorted_and_duplicate_indices() {
+        assert_eq!(coalesce_match_runs(&[6, 5, 5, 0]), vec![(0, 1), (5, 2)]);
+        assert_eq!(coalesce_match_runs(&[]), Vec::<(usize, usize)>::new());
+    }
+
+    #[test]
+    fn highlighted_runs_render_as_single_spans() {
+        let row = GenericDisplayRow {
+            name: "abcdefg".to_string(),
+            display_shortcut: None,
+            match_indices: Some(vec![0, 1, 2, 5, 6]),
+            is_current: false,
+            description: None,
+        };
+
+        let line = build_full_line(&row, 20);
+
+        assert_eq!(
+            line,
+            Line::from(vec!["abc".bold(), "de".into(), "fg".bold()])
+        );
+    }
+
+    #[test]
+    fn truncated_name_keeps_highlights_before_the_ellipsis() {
+        let row = GenericDisplayRow {
+            name: "abcdefg".to_string(),
+            display_shortcut: None,
+            match_indices: Some(vec![1, 3, 4]),
+            is_current: false,
+            description: None,
+        };
+
+        // desc_col 6 leaves room for four characters: `d` is the last shown
+        // and `e` (index 4) falls under the ellipsis.
+        let line = build_full_line(&row, 6);
+
+        assert_eq!(
+            line,
+            Line::from(vec![
+                "a".into(),
+                "b".bold(),
+                "c".into(),
+                "d".bold(),
+                "…".into()
+            ])
+        );
+    }
+}
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Instant;
 
 use codex_core::config::Config;
 use codex_core::config::types::Notifications;
@@ -23,6 +24,8 @@ use codex_core::protocol::EventMsg;
 use codex_core::protocol::ExecApprovalRequestEvent;
 use codex_core::protocol::ExecCommandBeginEvent;
 use codex_core::protocol::ExecCommandEndEvent;
+use codex_core::protocol::ExecCommandPausedEvent;
+use codex_core::protocol::ExecCommandResumedEvent;
 use codex_core::protocol::ExitedReviewModeEvent;
 use codex_core::protocol::ListCustomPromptsResponseEvent;
 use codex_core::protocol::McpListToolsResponseEvent;
@@ -95,7 +98,10 @@ use crate::render::renderable::Renderable;
 use crate::render::renderable::RenderableExt;
 use crate::render::renderable::RenderableItem;
 use crate::slash_command::SlashCommand;
+use crate::status::GitSummary;
+use crate::status::McpServerStatus;
 use crate::status::RateLimitSnapshotDisplay;
+use crate::status::RateLimitWindowKind;
 use crate::text_formatting::truncate_text;
 use crate::tui::FrameRequester;
 mod interrupts;
@@ -131,9 +137,18 @@ struct RunningCommand {
     is_user_shell_command: bool,
 }
 
+// A running command the user paused with `/pause`.
+struct PausedCommand {
+    call_id: String,
+    // Status header to restore once the command runs again.
+    previous_status_header: String,
+}
+
 const RATE_LIMIT_WARNING_THRESHOLDS: [f64; 3] = [75.0, 90.0, 95.0];
 const NUDGE_MODEL_SLUG: &str = "gpt-5-codex-mini";
 const RATE_LIMIT_SWITCH_PROMPT_THRESHOLD: f64 = 90.0;
+// Rate-limit snapshots kept for the usage trend in /status.
+const RATE_LIMIT_USAGE_HISTORY_LEN: usize = 24;
 
 #[derive(Default)]
 struct RateLimitWarningState {
@@ -220,6 +235,21 @@ pub(crate) fn get_limits_duration(windows_minutes: i64) -> String {
     }
 }
 
+/// History message announcing that `window` of `snapshot` just reset.
+fn rate_limit_reset_message(
+    snapshot: &RateLimitSnapshotDisplay,
+    window: RateLimitWindowKind,
+) -> String {
+    let window = match window {
+        RateLimitWindowKind::Primary => snapshot.primary.as_ref(),
+        RateLimitWindowKind::Secondary => snapshot.secondary.as_ref(),
+    };
+    match window.and_then(|window| window.window_minutes) {
+        Some(minutes) => format!("Your {} limit has reset.", get_limits_duration(minutes)),
+        None => "Your usage limit has reset.".to_string(),
+    }
+}
+
 /// Common initialization parameters shared by all `ChatWidget` constructors.
 pub(crate) struct ChatWidgetInit {
     pub(crate) config: Config,
@@ -250,7 +280,13 @@ pub(crate) struct ChatWidget {
     session_header: SessionHeader,
     initial_user_message: Option<UserMessage>,
     token_info: Option<TokenUsageInfo>,
+    // When this widget's session started, for the elapsed time in /status.
+    session_started_at: Instant,
+    // MCP connection state from the latest /mcp listing, for /status.
+    mcp_server_statuses: Option<Vec<McpServerStatus>>,
     rate_limit_snapshot: Option<RateLimitSnapshotDisplay>,
+    // Usage of the most urgent window from recent snapshots, oldest first.
+    rate_limit_usage_history: VecDeque<f64>,
     rate_limit_warnings: RateLimitWarningState,
     rate_limit_switch_prompt: RateLimitSwitchPromptState,
     // Stream lifecycle controller
@@ -267,6 +303,7 @@ pub(crate) struct ChatWidget {
     current_status_header: String,
     // Previous status header to restore after a transient stream retry.
     retry_status_header: Option<String>,
+    paused_command: Option<PausedCommand>,
     conversation_id: Option<ConversationId>,
     frame_requester: FrameRequester,
     // Whether to include the initial welcome banner on session configured
@@ -339,6 +376,11 @@ impl ChatWidget {
     fn on_session_configured(&mut self, event: codex_core::protocol::SessionConfiguredEvent) {
         self.bottom_pane
             .set_history_metadata(event.history_log_id, event.history_entry_count);
+        self.bottom_pane
+            .set_file_search_dir(self.config.cwd.clone());
+        if let Some(case_fold) = self.config.tui_file_search_case_fold {
+            self.bottom_pane.set_file_search_case_fold(case_fold);
+        }
         self.conversation_id = Some(event.session_id);
         self.current_rollout_path = Some(event.rollout_path.clone());
         let initial_messages = event.initial_messages.clone();
@@ -466,6 +508,7 @@ impl ChatWidget {
         // Mark task stopped and request redraw now that all content is in history.
         self.bottom_pane.set_task_running(false);
         self.running_commands.clear();
+        self.paused_command = None;
         self.request_redraw();
 
         // If there is a queued user message, send exactly one now to begin the next turn.
@@ -533,7 +576,30 @@ impl ChatWidget {
             }
 
             let display = crate::status::rate_limit_snapshot_display(&snapshot, Local::now());
-            self.rate_limit_snapshot = Some(display);
+            // Updates may carry only one window; keep the other from the
+            // previous snapshot.
+            let previous = self.rate_limit_snapshot.take();
+            let merged = match previous.as_ref() {
+                Some(existing) => existing.merge(&display),
+                None => display,
+            };
+            debug!(
+                "rate limits: {}",
+                crate::status::describe_rate_limit_transition(previous.as_ref(), Some(&merged))
+            );
+            let reset = previous
+                .as_ref()
+                .and_then(|previous| crate::status::detect_rate_limit_reset(previous, &merged));
+            if let Some(window) = reset {
+                self.add_info_message(rate_limit_reset_message(&merged, window), None);
+            }
+            if let Some(window) = merged.most_urgent_window() {
+                if self.rate_limit_usage_history.len() == RATE_LIMIT_USAGE_HISTORY_LEN {
+                    self.rate_limit_usage_history.pop_front();
+                }
+                self.rate_limit_usage_history.push_back(window.used_percent);
+            }
+            self.rate_limit_snapshot = Some(merged);
 
             if !warnings.is_empty() {
                 for warning in warnings {
@@ -541,10 +607,23 @@ impl ChatWidget {
                 }
                 self.request_redraw();
             }
-        } else {
-            self.rate_limit_snapshot = None;
+        } else if let Some(previous) = self.rate_limit_snapshot.take() {
+            debug!(
+                "rate limits: {}",
+                crate::status::describe_rate_limit_transition(Some(&previous), None)
+            );
         }
     }
+
+    /// Forget all rate-limit state, e.g. after the account changes, so the
+    /// next snapshot is treated like the first one of a session.
+    pub(crate) fn clear_rate_limits(&mut self) {
+        self.rate_limit_snapshot = None;
+        self.rate_limit_usage_history.clear();
+        self.rate_limit_warnings = RateLimitWarningState::default();
+        self.rate_limit_switch_prompt = RateLimitSwitchPromptState::default();
+    }
+
     /// Finalize any active exec as failed and stop/clear running UI state.
     fn finalize_turn(&mut self) {
         // Ensure any spinner is replaced by a red ✗ and flushed into history.
@@ -552,6 +631,7 @@ impl ChatWidget {
         // Reset running state and clear streaming buffers.
         self.bottom_pane.set_task_running(false);
         self.running_commands.clear();
+        self.paused_command = None;
         self.stream_controller = None;
         self.maybe_show_pending_rate_limit_prompt();
     }
@@ -667,7 +747,59 @@ impl ChatWidget {
         );
     }
 
+    fn on_exec_command_paused(&mut self, ev: ExecCommandPausedEvent) {
+        let previous_status_header = match self.paused_command.take() {
+            Some(paused) => paused.previous_status_header,
+            None => self.current_status_header.clone(),
+        };
+        self.paused_command = Some(PausedCommand {
+            call_id: ev.call_id,
+            previous_status_header,
+        });
+        self.set_status_header(String::from("Paused"));
+    }
+
+    fn on_exec_command_resumed(&mut self, ev: ExecCommandResumedEvent) {
+        self.clear_paused_command(&ev.call_id);
+    }
+
+    /// Restores the status header if `call_id` is the paused command.
+    fn clear_paused_command(&mut self, call_id: &str) {
+        if self
+            .paused_command
+            .as_ref()
+            .is_some_and(|paused| paused.call_id == call_id)
+            && let Some(paused) = self.paused_command.take()
+        {
+            self.set_status_header(paused.previous_status_header);
+        }
+    }
+
+    /// `/pause`: resumes the paused command, otherwise pauses the single
+    /// running command.
+    fn toggle_command_pause(&mut self) {
+        if let Some(paused) = &self.paused_command {
+            self.submit_op(Op::ResumeExecCommand {
+                call_id: paused.call_id.clone(),
+            });
+            return;
+        }
+        let mut call_ids = self.running_commands.keys();
+        match (call_ids.next(), call_ids.next()) {
+            (Some(call_id), None) => {
+                let call_id = call_id.clone();
+                self.submit_op(Op::PauseExecCommand { call_id });
+            }
+            (None, _) => self.add_info_message("No command is running.".to_string(), None),
+            (Some(_), Some(_)) => self.add_info_message(
+                "Several commands are running; /pause needs exactly one.".to_string(),
+                None,
+            ),
+        }
+    }
+
     fn on_exec_command_end(&mut self, ev: ExecCommandEndEvent) {
+        self.clear_paused_command(&ev.call_id);
         let ev2 = ev.clone();
         self.defer_or_handle(|q| q.push_exec_end(ev), |s| s.handle_exec_end_now(ev2));
     }
@@ -1036,8 +1168,11 @@ impl ChatWidget {
             ),
             token_info: None,
             rate_limit_snapshot: None,
+            rate_limit_usage_history: VecDeque::new(),
             rate_limit_warnings: RateLimitWarningState::default(),
             rate_limit_switch_prompt: RateLimitSwitchPromptState::default(),
+            session_started_at: Instant::now(),
+            mcp_server_statuses: None,
             stream_controller: None,
             running_commands: HashMap::new(),
             task_complete_pending: false,
@@ -1046,6 +1181,7 @@ impl ChatWidget {
             full_reasoning_buffer: String::new(),
             current_status_header: String::from("Working"),
             retry_status_header: None,
+            paused_command: None,
             conversation_id: None,
             queued_user_messages: VecDeque::new(),
             show_welcome_banner: true,
@@ -1103,8 +1239,11 @@ impl ChatWidget {
             ),
             token_info: None,
             rate_limit_snapshot: None,
+            rate_limit_usage_history: VecDeque::new(),
             rate_limit_warnings: RateLimitWarningState::default(),
             rate_limit_switch_prompt: RateLimitSwitchPromptState::default(),
+            session_started_at: Instant::now(),
+            mcp_server_statuses: None,
             stream_controller: None,
             running_commands: HashMap::new(),
             task_complete_pending: false,
@@ -1113,6 +1252,7 @@ impl ChatWidget {
             full_reasoning_buffer: String::new(),
             current_status_header: String::from("Working"),
             retry_status_header: None,
+            paused_command: None,
             conversation_id: None,
             queued_user_messages: VecDeque::new(),
             show_welcome_banner: true,
@@ -1271,6 +1411,9 @@ impl ChatWidget {
                 ) {
                     tracing::error!("failed to logout: {e}");
                 }
+                // The limits belonged to the account that just logged out;
+                // drop them so nothing rendered before exit shows stale usage.
+                self.clear_rate_limits();
                 self.request_exit();
             }
             SlashCommand::Undo => {
@@ -1297,11 +1440,29 @@ impl ChatWidget {
                 self.insert_str("@");
             }
             SlashCommand::Status => {
-                self.add_status_output();
+                // `git status` can be slow on large repositories, so gather it
+                // off the UI thread and render the card when it is done.
+                let cwd = self.config.cwd.clone();
+                let tx = self.app_event_tx.clone();
+                tokio::spawn(async move {
+                    let git = tokio::task::spawn_blocking(move || {
+                        crate::status::compose_git_summary(&cwd)
+                    })
+                    .await
+                    .ok()
+                    .flatten();
+                    tx.send(AppEvent::StatusGitSummary(git));
+                });
+            }
+            SlashCommand::StatusJson => {
+                self.add_status_json_output();
             }
             SlashCommand::Mcp => {
                 self.add_mcp_output();
             }
+            SlashCommand::Pause => {
+                self.toggle_command_pause();
+            }
             SlashCommand::Rollout => {
                 if let Some(path) = self.rollout_path() {
                     self.add_info_message(
@@ -1570,6 +1731,8 @@ impl ChatWidget {
                 self.on_entered_review_mode(review_request)
             }
             EventMsg::ExitedReviewMode(review) => self.on_exited_review_mode(review),
+            EventMsg::ExecCommandPaused(ev) => self.on_exec_command_paused(ev),
+            EventMsg::ExecCommandResumed(ev) => self.on_exec_command_resumed(ev),
             EventMsg::RawResponseItem(_)
             | EventMsg::ItemStarted(_)
             | EventMsg::ItemCompleted(_)
@@ -1700,7 +1863,7 @@ impl ChatWidget {
         self.request_redraw();
     }
 
-    pub(crate) fn add_status_output(&mut self) {
+    pub(crate) fn add_status_output(&mut self, git: Option<GitSummary>) {
         let default_usage = TokenUsage::default();
         let (total_usage, context_usage) = if let Some(ti) = &self.token_info {
             (&ti.total_token_usage, Some(&ti.last_token_usage))
@@ -1713,10 +1876,31 @@ impl ChatWidget {
             context_usage,
             &self.conversation_id,
             self.rate_limit_snapshot.as_ref(),
+            &crate::status::StatusSessionDetails {
+                started_at: Some(self.session_started_at),
+                mcp_servers: self.mcp_server_statuses.clone(),
+                git,
+                usage_history: self.rate_limit_usage_history.iter().copied().collect(),
+            },
             Local::now(),
         ));
     }
 
+    pub(crate) fn add_status_json_output(&mut self) {
+        let default_usage = TokenUsage::default();
+        let (total_usage, context_usage) = match &self.token_info {
+            Some(ti) => (&ti.total_token_usage, Some(&ti.last_token_usage)),
+            None => (&default_usage, None),
+        };
+        self.add_to_history(crate::status::new_status_json_output(
+            &self.config,
+            total_usage,
+            context_usage,
+            &self.conversation_id,
+            self.rate_limit_snapshot.as_ref(),
+        ));
+    }
+
     fn lower_cost_preset(&self) -> Option<ModelPreset> {
         let auth_mode = self.auth_manager.auth().map(|auth| auth.mode);
         builtin_model_presets(auth_mode)
@@ -2471,8 +2655,14 @@ impl ChatWidget {
     }
 
     /// Forward file-search results to the bottom pane.
-    pub(crate) fn apply_file_search_result(&mut self, query: String, matches: Vec<FileMatch>) {
-        self.bottom_pane.on_file_search_result(query, matches);
+    pub(crate) fn apply_file_search_result(
+        &mut self,
+        query: String,
+        matches: Vec<FileMatch>,
+        total_match_count: usize,
+    ) {
+        self.bottom_pane
+            .on_file_search_result(query, matches, total_match_count);
     }
 
     /// Handle Ctrl-C key press.
@@ -2527,6 +2717,24 @@ impl ChatWidget {
     }
 
     fn on_list_mcp_tools(&mut self, ev: McpListToolsResponseEvent) {
+        // A server counts as connected once it has reported tools or resources.
+        self.mcp_server_statuses = Some(
+            self.config
+                .mcp_servers
+                .keys()
+                .map(|name| {
+                    let prefix = format!("mcp__{name}__");
+                    McpServerStatus {
+                        name: name.clone(),
+                        connected: ev.tools.keys().any(|tool| tool.starts_with(&prefix))
+                            || ev
+                                .resources
+                                .get(name)
+                                .is_some_and(|resources| !resources.is_empty()),
+                    }
+                })
+                .collect(),
+        );
         self.add_to_history(history_cell::new_mcp_tools_output(
             &self.config,
             ev.tools,
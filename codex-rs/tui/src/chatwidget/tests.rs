@@ -23,6 +23,8 @@ use codex_core::protocol::EventMsg;
 use codex_core::protocol::ExecApprovalRequestEvent;
 use codex_core::protocol::ExecCommandBeginEvent;
 use codex_core::protocol::ExecCommandEndEvent;
+use codex_core::protocol::ExecCommandPausedEvent;
+use codex_core::protocol::ExecCommandResumedEvent;
 use codex_core::protocol::ExitedReviewModeEvent;
 use codex_core::protocol::FileChange;
 use codex_core::protocol::Op;
@@ -298,8 +300,11 @@ fn make_chatwidget_manual() -> (
         initial_user_message: None,
         token_info: None,
         rate_limit_snapshot: None,
+        rate_limit_usage_history: VecDeque::new(),
         rate_limit_warnings: RateLimitWarningState::default(),
         rate_limit_switch_prompt: RateLimitSwitchPromptState::default(),
+        session_started_at: Instant::now(),
+        mcp_server_statuses: None,
         stream_controller: None,
         running_commands: HashMap::new(),
         task_complete_pending: false,
@@ -308,6 +313,7 @@ fn make_chatwidget_manual() -> (
         full_reasoning_buffer: String::new(),
         current_status_header: String::from("Working"),
         retry_status_header: None,
+        paused_command: None,
         conversation_id: None,
         frame_requester: FrameRequester::test_dummy(),
         show_welcome_banner: true,
@@ -408,6 +414,19 @@ fn test_rate_limit_warnings_monthly() {
     );
 }
 
+#[test]
+fn limits_duration_labels_known_window_sizes() {
+    let labels: Vec<String> = [0, 60, 300, 1440, 10_080, 43_200, 525_600]
+        .into_iter()
+        .map(get_limits_duration)
+        .collect();
+
+    assert_eq!(
+        labels,
+        vec!["1h", "1h", "5h", "24h", "weekly", "monthly", "annual"]
+    );
+}
+
 #[test]
 fn rate_limit_switch_prompt_skips_when_on_lower_cost_model() {
     let (mut chat, _, _) = make_chatwidget_manual();
@@ -448,6 +467,80 @@ fn rate_limit_switch_prompt_shows_once_per_session() {
     ));
 }
 
+#[test]
+fn clear_rate_limits_restores_pristine_state() {
+    let (mut chat, _, _) = make_chatwidget_manual();
+    chat.config.model = "gpt-5".to_string();
+    chat.auth_manager =
+        AuthManager::from_auth_for_testing(CodexAuth::create_dummy_chatgpt_auth_for_testing());
+
+    chat.on_rate_limit_snapshot(Some(snapshot(95.0)));
+    assert!(chat.rate_limit_snapshot.is_some());
+
+    chat.clear_rate_limits();
+
+    assert!(chat.rate_limit_snapshot.is_none());
+    assert_eq!(
+        (
+            chat.rate_limit_warnings.primary_index,
+            chat.rate_limit_warnings.secondary_index
+        ),
+        (0, 0)
+    );
+    assert!(matches!(
+        chat.rate_limit_switch_prompt,
+        RateLimitSwitchPromptState::Idle
+    ));
+}
+
+#[test]
+fn rate_limit_reset_is_reported_once_in_history() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual();
+
+    chat.on_rate_limit_snapshot(Some(snapshot(90.0)));
+    chat.on_rate_limit_snapshot(Some(snapshot(85.0)));
+    let before_reset: Vec<String> = drain_insert_history(&mut rx)
+        .iter()
+        .map(|lines| lines_to_single_string(lines))
+        .collect();
+
+    chat.on_rate_limit_snapshot(Some(snapshot(5.0)));
+    chat.on_rate_limit_snapshot(Some(snapshot(6.0)));
+    let after_reset: Vec<String> = drain_insert_history(&mut rx)
+        .iter()
+        .map(|lines| lines_to_single_string(lines))
+        .collect();
+
+    let reset_message = "Your 1h limit has reset.";
+    assert!(!before_reset.iter().any(|cell| cell.contains(reset_message)));
+    assert_eq!(
+        after_reset
+            .iter()
+            .filter(|cell| cell.contains(reset_message))
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn rate_limit_usage_history_keeps_recent_snapshots() {
+    let (mut chat, _rx, _op_rx) = make_chatwidget_manual();
+
+    for percent in 0..RATE_LIMIT_USAGE_HISTORY_LEN + 2 {
+        chat.on_rate_limit_snapshot(Some(snapshot(percent as f64)));
+    }
+
+    assert_eq!(
+        chat.rate_limit_usage_history,
+        (2..RATE_LIMIT_USAGE_HISTORY_LEN + 2)
+            .map(|percent| percent as f64)
+            .collect::<VecDeque<_>>()
+    );
+
+    chat.clear_rate_limits();
+    assert!(chat.rate_limit_usage_history.is_empty());
+}
+
 #[test]
 fn rate_limit_switch_prompt_respects_hidden_notice() {
     let auth = CodexAuth::create_dummy_chatgpt_auth_for_testing();
@@ -979,6 +1072,20 @@ fn slash_exit_requests_exit() {
     assert_matches!(rx.try_recv(), Ok(AppEvent::ExitRequest));
 }
 
+#[test]
+fn slash_logout_clears_rate_limits_and_requests_exit() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual();
+    let codex_home = tempdir().expect("temp codex home");
+    chat.config.codex_home = codex_home.path().to_path_buf();
+    chat.on_rate_limit_snapshot(Some(snapshot(95.0)));
+    while rx.try_recv().is_ok() {}
+
+    chat.dispatch_command(SlashCommand::Logout);
+
+    assert!(chat.rate_limit_snapshot.is_none());
+    assert_matches!(rx.try_recv(), Ok(AppEvent::ExitRequest));
+}
+
 #[test]
 fn slash_undo_sends_op() {
     let (mut chat, mut rx, _op_rx) = make_chatwidget_manual();
@@ -2569,6 +2676,47 @@ fn plan_update_renders_history_cell() {
     assert!(blob.contains("Write tests"));
 }
 
+#[test]
+fn pause_command_toggles_running_command_and_status_header() {
+    let (mut chat, _rx, mut op_rx) = make_chatwidget_manual();
+    chat.on_task_started();
+    begin_exec(&mut chat, "call-1", "sleep 10");
+
+    chat.dispatch_command(SlashCommand::Pause);
+    assert_matches!(
+        op_rx.try_recv(),
+        Ok(Op::PauseExecCommand { call_id }) if call_id == "call-1"
+    );
+    chat.handle_codex_event(Event {
+        id: "sub-1".into(),
+        msg: EventMsg::ExecCommandPaused(ExecCommandPausedEvent {
+            call_id: "call-1".to_string(),
+        }),
+    });
+    let status = chat
+        .bottom_pane
+        .status_widget()
+        .expect("status indicator should be visible");
+    assert_eq!(status.header(), "Paused");
+
+    chat.dispatch_command(SlashCommand::Pause);
+    assert_matches!(
+        op_rx.try_recv(),
+        Ok(Op::ResumeExecCommand { call_id }) if call_id == "call-1"
+    );
+    chat.handle_codex_event(Event {
+        id: "sub-1".into(),
+        msg: EventMsg::ExecCommandResumed(ExecCommandResumedEvent {
+            call_id: "call-1".to_string(),
+        }),
+    });
+    let status = chat
+        .bottom_pane
+        .status_widget()
+        .expect("status indicator should be visible");
+    assert_eq!(status.header(), "Working");
+}
+
 #[test]
 fn stream_error_updates_status_indicator() {
     let (mut chat, mut rx, _op_rx) = make_chatwidget_manual();
@@ -11,7 +11,10 @@
 //!
 //! It works as follows:
 //!
-//! 1. First query starts a debounce timer.
+//! 1. First query starts a debounce timer. Its length adapts to the repo: it
+//!    is derived from how long the previous search took, so small trees search
+//!    instantly and large ones wait longer for the user to pause. Callers can
+//!    raise the floor with [`FileSearchManager::set_min_debounce`].
 //! 2. While the timer is pending, the latest query from the user is stored.
 //! 3. When the timer fires, it is cleared, and a search is done for the most
 //!    recent query.
@@ -27,6 +30,7 @@ use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::thread;
 use std::time::Duration;
+use std::time::Instant;
 
 use crate::app_event::AppEvent;
 use crate::app_event_sender::AppEventSender;
@@ -35,9 +39,19 @@ const MAX_FILE_SEARCH_RESULTS: NonZeroUsize = NonZeroUsize::new(8).unwrap();
 const NUM_FILE_SEARCH_THREADS: NonZeroUsize = NonZeroUsize::new(2).unwrap();
 
 /// How long to wait after a keystroke before firing the first search when none
-/// is currently running. Keeps early queries more meaningful.
+/// is currently running. Keeps early queries more meaningful. Used until a
+/// search has completed and [`debounce_for_search_time`] can take over.
 const FILE_SEARCH_DEBOUNCE: Duration = Duration::from_millis(100);
 
+/// Searches faster than this indicate a small repo; no debounce is applied.
+const FAST_SEARCH_THRESHOLD: Duration = Duration::from_millis(20);
+
+/// Searches slower than this indicate a large repo and get the long debounce.
+const SLOW_SEARCH_THRESHOLD: Duration = Duration::from_millis(200);
+
+/// Debounce used after slow searches.
+const LARGE_REPO_DEBOUNCE: Duration = Duration::from_millis(250);
+
 const ACTIVE_SEARCH_COMPLETE_POLL_INTERVAL: Duration = Duration::from_millis(20);
 
 /// State machine for file-search orchestration.
@@ -46,6 +60,8 @@ pub(crate) struct FileSearchManager {
     state: Arc<Mutex<SearchState>>,
 
     search_dir: PathBuf,
+    /// When `true`, results include files excluded by ignore files.
+    include_ignored: bool,
     app_tx: AppEventSender,
 }
 
@@ -58,6 +74,27 @@ struct SearchState {
 
     /// If there is an active search, this will be the query being searched.
     active_search: Option<ActiveSearch>,
+
+    /// Delay before the next scheduled search starts, adapted from the last
+    /// search time.
+    debounce: Duration,
+
+    /// Lower bound for `debounce` set by the caller.
+    min_debounce: Duration,
+}
+
+impl SearchState {
+    /// Delay actually applied before a scheduled search.
+    fn effective_debounce(&self) -> Duration {
+        self.debounce.max(self.min_debounce)
+    }
+
+    /// Whether a search scheduled at `scheduled_at` may start at `now`: the
+    /// debounce has elapsed and no other search is in flight.
+    fn search_due(&self, scheduled_at: Instant, now: Instant) -> bool {
+        now.saturating_duration_since(scheduled_at) >= self.effective_debounce()
+            && self.active_search.is_none()
+    }
 }
 
 struct ActiveSearch {
@@ -66,18 +103,28 @@ struct ActiveSearch {
 }
 
 impl FileSearchManager {
-    pub fn new(search_dir: PathBuf, tx: AppEventSender) -> Self {
+    pub fn new(search_dir: PathBuf, include_ignored: bool, tx: AppEventSender) -> Self {
         Self {
             state: Arc::new(Mutex::new(SearchState {
                 latest_query: String::new(),
                 is_search_scheduled: false,
                 active_search: None,
+                debounce: FILE_SEARCH_DEBOUNCE,
+                min_debounce: Duration::ZERO,
             })),
             search_dir,
+            include_ignored,
             app_tx: tx,
         }
     }
 
+    /// Never wait less than `min_debounce` before searching, e.g. for repos
+    /// the caller knows to be large. Applies from the next scheduled search.
+    pub fn set_min_debounce(&self, min_debounce: Duration) {
+        #[expect(clippy::unwrap_used)]
+        self.state.lock().unwrap().min_debounce = min_debounce;
+    }
+
     /// Call whenever the user edits the `@` token.
     pub fn on_user_query(&self, query: String) {
         {
@@ -116,14 +163,22 @@ impl FileSearchManager {
         // debounce timer.
         let state = self.state.clone();
         let search_dir = self.search_dir.clone();
+        let include_ignored = self.include_ignored;
         let tx_clone = self.app_tx.clone();
+        let scheduled_at = Instant::now();
         thread::spawn(move || {
             // Always do a minimum debounce, but then poll until the
             // `active_search` is cleared.
-            thread::sleep(FILE_SEARCH_DEBOUNCE);
+            #[expect(clippy::unwrap_used)]
+            let debounce = state.lock().unwrap().effective_debounce();
+            thread::sleep(debounce);
             loop {
                 #[expect(clippy::unwrap_used)]
-                if state.lock().unwrap().active_search.is_none() {
+                if state
+                    .lock()
+                    .unwrap()
+                    .search_due(scheduled_at, Instant::now())
+                {
                     break;
                 }
                 thread::sleep(ACTIVE_SEARCH_COMPLETE_POLL_INTERVAL);
@@ -148,6 +203,7 @@ impl FileSearchManager {
             FileSearchManager::spawn_file_search(
                 query,
                 search_dir,
+                include_ignored,
                 tx_clone,
                 cancellation_token,
                 state,
@@ -158,13 +214,15 @@ impl FileSearchManager {
     fn spawn_file_search(
         query: String,
         search_dir: PathBuf,
+        include_ignored: bool,
         tx: AppEventSender,
         cancellation_token: Arc<AtomicBool>,
         search_state: Arc<Mutex<SearchState>>,
     ) {
         let compute_indices = true;
         std::thread::spawn(move || {
-            let matches = file_search::run(
+            let started_at = Instant::now();
+            let (matches, total_match_count) = file_search::run(
                 &query,
                 MAX_FILE_SEARCH_RESULTS,
                 &search_dir,
@@ -172,14 +230,20 @@ impl FileSearchManager {
                 NUM_FILE_SEARCH_THREADS,
                 cancellation_token.clone(),
                 compute_indices,
+                !include_ignored,
                 true,
             )
-            .map(|res| res.matches)
+            .map(|res| (res.matches, res.total_match_count))
             .unwrap_or_default();
+            let elapsed = started_at.elapsed();
 
             let is_cancelled = cancellation_token.load(Ordering::Relaxed);
             if !is_cancelled {
-                tx.send(AppEvent::FileSearchResult { query, matches });
+                tx.send(AppEvent::FileSearchResult {
+                    query,
+                    matches,
+                    total_match_count,
+                });
             }
 
             // Reset the active search state. Do a pointer comparison to verify
@@ -188,6 +252,11 @@ impl FileSearchManager {
             {
                 #[expect(clippy::unwrap_used)]
                 let mut st = search_state.lock().unwrap();
+                if !is_cancelled {
+                    // Cancelled searches stop early, so only completed ones
+                    // say anything about the repo size.
+                    st.debounce = debounce_for_search_time(elapsed);
+                }
                 if let Some(active_search) = &st.active_search
                     && Arc::ptr_eq(&active_search.cancellation_token, &cancellation_token)
                 {
@@ -197,3 +266,87 @@ impl FileSearchManager {
         });
     }
 }
+
+/// Debounce to apply before the next search given how long the last complete
+/// search took, a cheap proxy for repo size: none for small trees, the default
+/// for mid-sized ones, and a longer pause for large ones.
+fn debounce_for_search_time(elapsed: Duration) -> Duration {
+    if elapsed < FAST_SEARCH_THRESHOLD {
+        Duration::ZERO
+    } else if elapsed < SLOW_SEARCH_THRESHOLD {
+        FILE_SEARCH_DEBOUNCE
+    } else {
+        LARGE_REPO_DEBOUNCE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn search_state(debounce: Duration, min_debounce: Duration) -> SearchState {
+        SearchState {
+            latest_query: String::new(),
+            is_search_scheduled: true,
+            active_search: None,
+            debounce,
+            min_debounce,
+        }
+    }
+
+    #[test]
+    fn min_debounce_delays_emission_for_fast_repos() {
+        let scheduled_at = Instant::now();
+        let at = |ms: u64| scheduled_at + Duration::from_millis(ms);
+        let instant = search_state(Duration::ZERO, Duration::ZERO);
+        let floored = search_state(Duration::ZERO, Duration::from_millis(300));
+
+        assert_eq!(
+            [0, 299, 300].map(|ms| instant.search_due(scheduled_at, at(ms))),
+            [true, true, true]
+        );
+        assert_eq!(
+            [0, 299, 300].map(|ms| floored.search_due(scheduled_at, at(ms))),
+            [false, false, true]
+        );
+    }
+
+    #[test]
+    fn adaptive_debounce_wins_over_a_lower_minimum() {
+        let scheduled_at = Instant::now();
+        let at = |ms: u64| scheduled_at + Duration::from_millis(ms);
+        let mut state = search_state(LARGE_REPO_DEBOUNCE, Duration::from_millis(50));
+
+        assert_eq!(
+            [50, 249, 250].map(|ms| state.search_due(scheduled_at, at(ms))),
+            [false, false, true]
+        );
+
+        state.active_search = Some(ActiveSearch {
+            query: "foo".to_string(),
+            cancellation_token: Arc::new(AtomicBool::new(false)),
+        });
+        assert!(!state.search_due(scheduled_at, at(1_000)));
+    }
+
+    #[test]
+    fn debounce_grows_with_search_time() {
+        let debounces: Vec<Duration> = [0, 19, 20, 199, 200, 5_000]
+            .into_iter()
+            .map(|ms| debounce_for_search_time(Duration::from_millis(ms)))
+            .collect();
+
+        assert_eq!(
+            debounces,
+            vec![
+                Duration::ZERO,
+                Duration::ZERO,
+                FILE_SEARCH_DEBOUNCE,
+                FILE_SEARCH_DEBOUNCE,
+                LARGE_REPO_DEBOUNCE,
+                LARGE_REPO_DEBOUNCE,
+            ]
+        );
+    }
+}
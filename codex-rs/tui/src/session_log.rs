@@ -154,13 +154,18 @@ pub(crate) fn log_inbound_app_event(event: &AppEvent) {
             });
             LOGGER.write_json_line(value);
         }
-        AppEvent::FileSearchResult { query, matches } => {
+        AppEvent::FileSearchResult {
+            query,
+            matches,
+            total_match_count,
+        } => {
             let value = json!({
                 "ts": now_ts(),
                 "dir": "to_tui",
                 "kind": "file_search_result",
                 "query": query,
                 "matches": matches.len(),
+                "total_match_count": total_match_count,
             });
             LOGGER.write_json_line(value);
         }
@@ -22,7 +22,9 @@ pub enum SlashCommand {
     Diff,
     Mention,
     Status,
+    StatusJson,
     Mcp,
+    Pause,
     Logout,
     Quit,
     Exit,
@@ -45,9 +47,11 @@ impl SlashCommand {
             SlashCommand::Diff => "show git diff (including untracked files)",
             SlashCommand::Mention => "mention a file",
             SlashCommand::Status => "show current session configuration and token usage",
+            SlashCommand::StatusJson => "print /status as JSON for scripts and status bars",
             SlashCommand::Model => "choose what model and reasoning effort to use",
             SlashCommand::Approvals => "choose what Codex can do without approval",
             SlashCommand::Mcp => "list configured MCP tools",
+            SlashCommand::Pause => "pause or resume the running command",
             SlashCommand::Logout => "log out of Codex",
             SlashCommand::Rollout => "print the rollout file path",
             SlashCommand::TestApproval => "test approval request",
@@ -74,7 +78,9 @@ impl SlashCommand {
             SlashCommand::Diff
             | SlashCommand::Mention
             | SlashCommand::Status
+            | SlashCommand::StatusJson
             | SlashCommand::Mcp
+            | SlashCommand::Pause
             | SlashCommand::Feedback
             | SlashCommand::Quit
             | SlashCommand::Exit => true,
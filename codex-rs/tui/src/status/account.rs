@@ -1,8 +1,23 @@
-#[derive(Debug, Clone)]
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
 pub(crate) enum StatusAccountDisplay {
+    #[serde(rename = "chatgpt")]
     ChatGpt {
         email: Option<String>,
         plan: Option<String>,
     },
     ApiKey,
+    /// Credentials exist but could not be read or parsed.
+    Unknown {
+        reason: String,
+    },
+}
+
+/// One configured identity and whether it is the one requests use.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct StatusAccountEntry {
+    pub account: StatusAccountDisplay,
+    pub active: bool,
 }
@@ -7,33 +7,58 @@ use chrono::DateTime;
 use chrono::Local;
 use codex_common::create_config_summary_entries;
 use codex_core::config::Config;
-use codex_core::protocol::SandboxPolicy;
+use codex_core::git_info::get_git_repo_root;
 use codex_core::protocol::TokenUsage;
 use codex_protocol::ConversationId;
 use ratatui::prelude::*;
 use ratatui::style::Stylize;
 use std::collections::BTreeSet;
 use std::path::PathBuf;
+use std::time::Instant;
+use unicode_width::UnicodeWidthStr;
 
 use super::account::StatusAccountDisplay;
 use super::format::FieldFormatter;
 use super::format::line_display_width;
 use super::format::push_label;
 use super::format::truncate_line_to_width;
-use super::helpers::compose_account_display;
+use super::helpers::GitSummary;
+use super::helpers::McpServerStatus;
+use super::helpers::compose_account_displays;
 use super::helpers::compose_agents_summary;
+use super::helpers::compose_approval_display;
+use super::helpers::compose_compact_status_line;
+use super::helpers::compose_mcp_summary;
 use super::helpers::compose_model_display;
+use super::helpers::compose_sandbox_display;
+use super::helpers::estimate_cost;
 use super::helpers::format_directory_display;
+use super::helpers::format_directory_display_git_relative;
+use super::helpers::format_email_display;
+use super::helpers::format_git_summary;
+use super::helpers::format_session_elapsed;
 use super::helpers::format_tokens_compact;
 use super::rate_limits::RateLimitSnapshotDisplay;
 use super::rate_limits::StatusRateLimitData;
 use super::rate_limits::StatusRateLimitRow;
 use super::rate_limits::compose_rate_limit_data;
+use super::rate_limits::format_reset_suffix;
 use super::rate_limits::format_status_limit_summary;
 use super::rate_limits::render_status_limit_progress_bar;
+use super::sparkline::UsageSparkline;
 use crate::wrapping::RtOptions;
 use crate::wrapping::word_wrap_lines;
 
+/// Below this many columns the bordered card is unreadable, so `/status`
+/// renders a single [`compose_compact_status_line`] instead.
+const COMPACT_STATUS_CARD_MAX_WIDTH: u16 = 40;
+
+/// MCP row value before any `/mcp` listing has reported connections.
+const MCP_NOT_CHECKED_MESSAGE: &str = "not checked yet (run /mcp)";
+
+/// Appended to stored identities that requests do not currently use.
+const INACTIVE_ACCOUNT_SUFFIX: &str = " (inactive)";
+
 #[derive(Debug, Clone)]
 struct StatusContextWindowData {
     percent_remaining: i64,
@@ -46,21 +71,52 @@ pub(crate) struct StatusTokenUsageData {
     total: i64,
     input: i64,
     output: i64,
+    /// Set when `tui.model_pricing` has an entry for the session's model.
+    estimated_cost: Option<String>,
     context_window: Option<StatusContextWindowData>,
 }
 
+/// Facts about the running session that the card shows but `Config` does not
+/// know. The default shows none of them.
+#[derive(Debug, Default)]
+pub(crate) struct StatusSessionDetails {
+    /// When the session started; shown as elapsed time after the session id.
+    pub started_at: Option<Instant>,
+    /// Connection state of the MCP servers from the latest `/mcp` listing, or
+    /// `None` when no listing has arrived yet.
+    pub mcp_servers: Option<Vec<McpServerStatus>>,
+    /// Git context of the working directory; `None` outside a repository.
+    pub git: Option<GitSummary>,
+    /// Recent usage percentages of the most urgent rate-limit window, oldest
+    /// first; drawn as a trend below the limit rows.
+    pub usage_history: Vec<f64>,
+}
+
 #[derive(Debug)]
 struct StatusHistoryCell {
     model_name: String,
     model_details: Vec<String>,
     directory: PathBuf,
+    /// Repository root containing `directory`; the Directory row is shown
+    /// relative to it.
+    git_root: Option<PathBuf>,
+    git_summary: Option<String>,
     approval: String,
     sandbox: String,
     agents_summary: String,
+    /// Set when `Config` enables at least one MCP server.
+    mcp_summary: Option<String>,
     account: Option<StatusAccountDisplay>,
+    /// Other stored identities that requests do not currently use.
+    inactive_accounts: Vec<StatusAccountDisplay>,
     session_id: Option<String>,
+    /// Time since the session started, e.g. `1h 5m`.
+    session_elapsed: Option<String>,
     token_usage: StatusTokenUsageData,
     rate_limits: StatusRateLimitData,
+    /// Usage of the window closest to its limit, for the compact line.
+    urgent_used_percent: Option<f64>,
+    usage_history: Vec<f64>,
 }
 
 pub(crate) fn new_status_output(
@@ -69,6 +125,7 @@ pub(crate) fn new_status_output(
     context_usage: Option<&TokenUsage>,
     session_id: &Option<ConversationId>,
     rate_limits: Option<&RateLimitSnapshotDisplay>,
+    session: &StatusSessionDetails,
     now: DateTime<Local>,
 ) -> CompositeHistoryCell {
     let command = PlainHistoryCell::new(vec!["/status".magenta().into()]);
@@ -78,6 +135,7 @@ pub(crate) fn new_status_output(
         context_usage,
         session_id,
         rate_limits,
+        session,
         now,
     );
 
@@ -91,23 +149,36 @@ impl StatusHistoryCell {
         context_usage: Option<&TokenUsage>,
         session_id: &Option<ConversationId>,
         rate_limits: Option<&RateLimitSnapshotDisplay>,
+        session: &StatusSessionDetails,
         now: DateTime<Local>,
     ) -> Self {
         let config_entries = create_config_summary_entries(config);
-        let (model_name, model_details) = compose_model_display(config, &config_entries);
-        let approval = config_entries
-            .iter()
-            .find(|(k, _)| *k == "approval")
-            .map(|(_, v)| v.clone())
-            .unwrap_or_else(|| "<unknown>".to_string());
-        let sandbox = match &config.sandbox_policy {
-            SandboxPolicy::DangerFullAccess => "danger-full-access".to_string(),
-            SandboxPolicy::ReadOnly => "read-only".to_string(),
-            SandboxPolicy::WorkspaceWrite { .. } => "workspace-write".to_string(),
-        };
+        // Context usage already gets its own row on the card.
+        let (model_name, model_details) = compose_model_display(config, &config_entries, None);
+        let approval = compose_approval_display(&config_entries);
+        let sandbox = compose_sandbox_display(&config.sandbox_policy);
         let agents_summary = compose_agents_summary(config);
-        let account = compose_account_display(config);
+        let mcp_summary = config
+            .mcp_servers
+            .values()
+            .any(|server| server.enabled)
+            .then(|| match session.mcp_servers.as_deref() {
+                Some(statuses) => compose_mcp_summary(config, statuses),
+                None => MCP_NOT_CHECKED_MESSAGE.to_string(),
+            });
+        let mut account = None;
+        let mut inactive_accounts = Vec::new();
+        for entry in compose_account_displays(config) {
+            if entry.active {
+                account = Some(entry.account);
+            } else {
+                inactive_accounts.push(entry.account);
+            }
+        }
         let session_id = session_id.as_ref().map(std::string::ToString::to_string);
+        let session_elapsed = session
+            .started_at
+            .map(|started_at| format_session_elapsed(started_at, Instant::now()));
         let context_window = config.model_context_window.and_then(|window| {
             context_usage.map(|usage| StatusContextWindowData {
                 percent_remaining: usage.percent_of_context_window_remaining(window),
@@ -116,25 +187,74 @@ impl StatusHistoryCell {
             })
         });
 
+        let input = total_usage.non_cached_input();
+        let output = total_usage.output_tokens;
+        let estimated_cost = config
+            .tui_model_pricing
+            .get(&config.model)
+            .map(|pricing| estimate_cost(input, output, pricing));
         let token_usage = StatusTokenUsageData {
             total: total_usage.blended_total(),
-            input: total_usage.non_cached_input(),
-            output: total_usage.output_tokens,
+            input,
+            output,
+            estimated_cost,
             context_window,
         };
-        let rate_limits = compose_rate_limit_data(rate_limits, now);
+        let urgent_used_percent = rate_limits
+            .and_then(RateLimitSnapshotDisplay::most_urgent_window)
+            .map(|window| window.used_percent);
+        let rate_limits = compose_rate_limit_data(rate_limits, now, config.tui_limit_glyphs);
 
         Self {
             model_name,
             model_details,
             directory: config.cwd.clone(),
+            git_root: session
+                .git
+                .as_ref()
+                .and_then(|_| get_git_repo_root(&config.cwd)),
+            git_summary: session.git.as_ref().map(format_git_summary),
             approval,
             sandbox,
             agents_summary,
+            mcp_summary,
             account,
+            inactive_accounts,
             session_id,
+            session_elapsed,
             token_usage,
             rate_limits,
+            urgent_used_percent,
+            usage_history: session.usage_history.clone(),
+        }
+    }
+
+    /// Token usage (and its cost estimate) is hidden only for ChatGPT
+    /// subscribers.
+    fn shows_token_usage(&self) -> bool {
+        !matches!(self.account, Some(StatusAccountDisplay::ChatGpt { .. }))
+    }
+
+    fn account_value(&self, account: &StatusAccountDisplay, value_width: usize) -> String {
+        match account {
+            StatusAccountDisplay::ChatGpt { email, plan } => match (email, plan) {
+                (Some(email), Some(plan)) => {
+                    let plan_width = UnicodeWidthStr::width(plan.as_str()) + 3;
+                    let email_width = value_width.saturating_sub(plan_width);
+                    format!(
+                        "{} ({plan})",
+                        format_email_display(email, Some(email_width))
+                    )
+                }
+                (Some(email), None) => format_email_display(email, Some(value_width)),
+                (None, Some(plan)) => plan.clone(),
+                (None, None) => "ChatGPT".to_string(),
+            },
+            StatusAccountDisplay::ApiKey if self.inactive_accounts.is_empty() => {
+                "API key configured (run codex login to use ChatGPT)".to_string()
+            }
+            StatusAccountDisplay::ApiKey => "API key configured (used before ChatGPT)".to_string(),
+            StatusAccountDisplay::Unknown { reason } => format!("Unknown ({reason})"),
         }
     }
 
@@ -220,8 +340,9 @@ impl StatusHistoryCell {
             let base_spans = formatter.full_spans(row.label.as_str(), value_spans);
             let base_line = Line::from(base_spans.clone());
 
-            if let Some(resets_at) = row.resets_at.as_ref() {
-                let resets_span = Span::from(format!("(resets {resets_at})")).dim();
+            let resets = format_reset_suffix(row.resets_at.as_deref());
+            if !resets.is_empty() {
+                let resets_span = Span::from(resets).dim();
                 let mut inline_spans = base_spans.clone();
                 inline_spans.push(Span::from(" ").dim());
                 inline_spans.push(resets_span.clone());
@@ -264,6 +385,15 @@ impl StatusHistoryCell {
 
 impl HistoryCell for StatusHistoryCell {
     fn display_lines(&self, width: u16) -> Vec<Line<'static>> {
+        if width < COMPACT_STATUS_CARD_MAX_WIDTH {
+            return vec![Line::from(compose_compact_status_line(
+                &self.model_name,
+                &self.directory,
+                self.urgent_used_percent,
+                usize::from(width),
+            ))];
+        }
+
         let mut lines: Vec<Line<'static>> = Vec::new();
         lines.push(Line::from(vec![
             Span::from(format!("{}>_ ", FieldFormatter::INDENT)).dim(),
@@ -278,18 +408,6 @@ impl HistoryCell for StatusHistoryCell {
             return Vec::new();
         }
 
-        let account_value = self.account.as_ref().map(|account| match account {
-            StatusAccountDisplay::ChatGpt { email, plan } => match (email, plan) {
-                (Some(email), Some(plan)) => format!("{email} ({plan})"),
-                (Some(email), None) => email.clone(),
-                (None, Some(plan)) => plan.clone(),
-                (None, None) => "ChatGPT".to_string(),
-            },
-            StatusAccountDisplay::ApiKey => {
-                "API key configured (run codex login to use ChatGPT)".to_string()
-            }
-        });
-
         let mut labels: Vec<String> =
             vec!["Model", "Directory", "Approval", "Sandbox", "Agents.md"]
                 .into_iter()
@@ -297,21 +415,38 @@ impl HistoryCell for StatusHistoryCell {
                 .collect();
         let mut seen: BTreeSet<String> = labels.iter().cloned().collect();
 
-        if account_value.is_some() {
+        if self.git_summary.is_some() {
+            push_label(&mut labels, &mut seen, "Git");
+        }
+        if self.mcp_summary.is_some() {
+            push_label(&mut labels, &mut seen, "MCP servers");
+        }
+        if self.account.is_some() {
             push_label(&mut labels, &mut seen, "Account");
         }
         if self.session_id.is_some() {
             push_label(&mut labels, &mut seen, "Session");
         }
         push_label(&mut labels, &mut seen, "Token usage");
+        if self.shows_token_usage() && self.token_usage.estimated_cost.is_some() {
+            push_label(&mut labels, &mut seen, "Est. cost");
+        }
         if self.token_usage.context_window.is_some() {
             push_label(&mut labels, &mut seen, "Context window");
         }
         self.collect_rate_limit_labels(&mut seen, &mut labels);
+        if self.usage_history.len() >= 2 {
+            push_label(&mut labels, &mut seen, "Usage trend");
+        }
 
         let formatter = FieldFormatter::from_labels(labels.iter().map(String::as_str));
         let value_width = formatter.value_width(available_inner_width);
 
+        let account_value = self
+            .account
+            .as_ref()
+            .map(|account| self.account_value(account, value_width));
+
         let note_first_line = Line::from(vec![
             Span::from("Visit ").cyan(),
             "https://chatgpt.com/codex/settings/usage"
@@ -336,26 +471,50 @@ impl HistoryCell for StatusHistoryCell {
             model_spans.push(Span::from(")").dim());
         }
 
-        let directory_value = format_directory_display(&self.directory, Some(value_width));
+        let directory_value = match self.git_root.as_deref() {
+            Some(git_root) => {
+                format_directory_display_git_relative(&self.directory, git_root, Some(value_width))
+            }
+            None => format_directory_display(&self.directory, Some(value_width)),
+        };
 
         lines.push(formatter.line("Model", model_spans));
         lines.push(formatter.line("Directory", vec![Span::from(directory_value)]));
+        if let Some(git_summary) = self.git_summary.as_ref() {
+            lines.push(formatter.line("Git", vec![Span::from(git_summary.clone())]));
+        }
         lines.push(formatter.line("Approval", vec![Span::from(self.approval.clone())]));
         lines.push(formatter.line("Sandbox", vec![Span::from(self.sandbox.clone())]));
         lines.push(formatter.line("Agents.md", vec![Span::from(self.agents_summary.clone())]));
+        if let Some(mcp_summary) = self.mcp_summary.as_ref() {
+            lines.push(formatter.line("MCP servers", vec![Span::from(mcp_summary.clone())]));
+        }
 
         if let Some(account_value) = account_value {
             lines.push(formatter.line("Account", vec![Span::from(account_value)]));
+            let inactive_width = value_width.saturating_sub(INACTIVE_ACCOUNT_SUFFIX.len());
+            for account in &self.inactive_accounts {
+                let value = self.account_value(account, inactive_width);
+                lines.push(formatter.continuation(vec![
+                    Span::from(format!("{value}{INACTIVE_ACCOUNT_SUFFIX}")).dim(),
+                ]));
+            }
         }
 
         if let Some(session) = self.session_id.as_ref() {
-            lines.push(formatter.line("Session", vec![Span::from(session.clone())]));
+            let mut session_spans = vec![Span::from(session.clone())];
+            if let Some(elapsed) = self.session_elapsed.as_ref() {
+                session_spans.push(Span::from(format!(" ({elapsed})")).dim());
+            }
+            lines.push(formatter.line("Session", session_spans));
         }
 
         lines.push(Line::from(Vec::<Span<'static>>::new()));
-        // Hide token usage only for ChatGPT subscribers
-        if !matches!(self.account, Some(StatusAccountDisplay::ChatGpt { .. })) {
+        if self.shows_token_usage() {
             lines.push(formatter.line("Token usage", self.token_usage_spans()));
+            if let Some(cost) = self.token_usage.estimated_cost.as_ref() {
+                lines.push(formatter.line("Est. cost", vec![Span::from(cost.clone())]));
+            }
         }
 
         if let Some(spans) = self.context_window_spans() {
@@ -363,6 +522,9 @@ impl HistoryCell for StatusHistoryCell {
         }
 
         lines.extend(self.rate_limit_lines(available_inner_width, &formatter));
+        if let Some(trend) = UsageSparkline::new(&self.usage_history).line(value_width) {
+            lines.push(formatter.line("Usage trend", trend.spans));
+        }
 
         let content_width = lines.iter().map(line_display_width).max().unwrap_or(0);
         let inner_width = content_width.min(available_inner_width);
@@ -19,11 +19,7 @@ impl FieldFormatter {
     where
         S: AsRef<str>,
     {
-        let label_width = labels
-            .into_iter()
-            .map(|label| UnicodeWidthStr::width(label.as_ref()))
-            .max()
-            .unwrap_or(0);
+        let label_width = status_max_width(labels);
         let indent_width = UnicodeWidthStr::width(Self::INDENT);
         let value_offset = indent_width + label_width + 1 + 3;
 
@@ -98,6 +94,19 @@ pub(crate) fn line_display_width(line: &Line<'static>) -> usize {
         .sum()
 }
 
+/// Widest display width across `lines`, counting wide characters as two
+/// cells. Returns 0 when there are no lines.
+pub(crate) fn status_max_width<S>(lines: impl IntoIterator<Item = S>) -> usize
+where
+    S: AsRef<str>,
+{
+    lines
+        .into_iter()
+        .map(|line| UnicodeWidthStr::width(line.as_ref()))
+        .max()
+        .unwrap_or(0)
+}
+
 pub(crate) fn truncate_line_to_width(line: Line<'static>, max_width: usize) -> Line<'static> {
     if max_width == 0 {
         return Line::from(Vec::<Span<'static>>::new());
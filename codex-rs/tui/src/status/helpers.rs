@@ -2,104 +2,394 @@ use crate::exec_command::relativize_to_home;
 use crate::text_formatting;
 use chrono::DateTime;
 use chrono::Local;
+use codex_app_server_protocol::AuthMode;
+use codex_common::model_presets::builtin_model_presets;
 use codex_core::auth::load_auth_dot_json;
 use codex_core::config::Config;
+use codex_core::config::types::ModelPricing;
 use codex_core::project_doc::discover_project_doc_paths;
+use codex_core::protocol::SandboxPolicy;
+use codex_core::protocol::TokenUsage;
+use codex_core::protocol_config_types::ReasoningEffort;
+use std::collections::HashMap;
 use std::path::Path;
+use std::time::Instant;
+use unicode_width::UnicodeWidthChar;
 use unicode_width::UnicodeWidthStr;
 
 use super::account::StatusAccountDisplay;
+use super::account::StatusAccountEntry;
+
+/// Shown for `Agents.md` when no project docs are found and
+/// `tui.agents_md_empty_message` is unset.
+const DEFAULT_AGENTS_EMPTY_MESSAGE: &str = "<none>";
+
+/// Shown for MCP servers when none are connected.
+const MCP_SUMMARY_EMPTY_MESSAGE: &str = "<none>";
+
+/// Server names listed before the MCP summary collapses into `+N more`.
+const MAX_MCP_SUMMARY_NAMES: usize = 3;
 
 fn normalize_agents_display_path(path: &Path) -> String {
     dunce::simplified(path).display().to_string()
 }
 
+/// Reasoning-summaries setting parsed from the `reasoning summaries` entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum SummariesState {
+    Off,
+    Auto,
+    Detailed,
+    Other(String),
+}
+
+impl SummariesState {
+    /// Parses the human-readable setting; returns `None` when it is blank.
+    pub(crate) fn parse(summary: &str) -> Option<Self> {
+        let summary = summary.trim();
+        if summary.is_empty() {
+            None
+        } else if summary.eq_ignore_ascii_case("none") || summary.eq_ignore_ascii_case("off") {
+            Some(Self::Off)
+        } else if summary.eq_ignore_ascii_case("auto") {
+            Some(Self::Auto)
+        } else if summary.eq_ignore_ascii_case("detailed") {
+            Some(Self::Detailed)
+        } else {
+            Some(Self::Other(summary.to_string()))
+        }
+    }
+
+    fn label(&self) -> String {
+        match self {
+            Self::Off => "summaries off".to_string(),
+            Self::Auto => "summaries auto".to_string(),
+            Self::Detailed => "summaries detailed".to_string(),
+            Self::Other(summary) => format!("summaries {}", summary.to_ascii_lowercase()),
+        }
+    }
+}
+
+/// Returns the model name and its detail labels. When `context_usage` is known
+/// and the model's context window is configured, a `context used/window`
+/// detail is appended.
 pub(crate) fn compose_model_display(
     config: &Config,
     entries: &[(&str, String)],
+    context_usage: Option<&TokenUsage>,
 ) -> (String, Vec<String>) {
+    let (model, details, _) =
+        compose_model_display_with_summaries(config, entries, context_usage, None);
+    (model, details)
+}
+
+/// Like [`compose_model_display`], but also returns the parsed
+/// reasoning-summaries setting so callers need not re-parse the labels.
+/// `effort_aliases` maps lowercase effort names (e.g. `"minimal"`) to the text
+/// shown instead; efforts without an alias render as their lowercase name.
+pub(crate) fn compose_model_display_with_summaries(
+    config: &Config,
+    entries: &[(&str, String)],
+    context_usage: Option<&TokenUsage>,
+    effort_aliases: Option<&HashMap<String, String>>,
+) -> (String, Vec<String>, Option<SummariesState>) {
+    let display_effort = |effort: &str| {
+        let effort = effort.to_ascii_lowercase();
+        effort_aliases
+            .and_then(|aliases| aliases.get(&effort))
+            .cloned()
+            .unwrap_or(effort)
+    };
     let mut details: Vec<String> = Vec::new();
     if let Some((_, effort)) = entries.iter().find(|(k, _)| *k == "reasoning effort") {
-        details.push(format!("reasoning {}", effort.to_ascii_lowercase()));
+        details.push(format!("reasoning {}", display_effort(effort)));
+    } else if let Some(effort) = default_reasoning_effort(config) {
+        details.push(format!(
+            "reasoning {} (default)",
+            display_effort(&effort.to_string())
+        ));
     }
-    if let Some((_, summary)) = entries.iter().find(|(k, _)| *k == "reasoning summaries") {
-        let summary = summary.trim();
-        if summary.eq_ignore_ascii_case("none") || summary.eq_ignore_ascii_case("off") {
-            details.push("summaries off".to_string());
-        } else if !summary.is_empty() {
-            details.push(format!("summaries {}", summary.to_ascii_lowercase()));
-        }
+    let summaries = entries
+        .iter()
+        .find(|(k, _)| *k == "reasoning summaries")
+        .and_then(|(_, summary)| SummariesState::parse(summary));
+    if let Some(summaries) = &summaries {
+        details.push(summaries.label());
+    }
+    if let (Some(usage), Some(window)) = (context_usage, config.model_context_window) {
+        details.push(format!(
+            "context {}",
+            format_usage_line(usage.tokens_in_context_window(), window)
+        ));
     }
 
-    (config.model.clone(), details)
+    (config.model.clone(), details, summaries)
+}
+
+/// Effort the model runs with when the summary entries omit it: the configured
+/// effort if any, otherwise the built-in preset default for the model.
+fn default_reasoning_effort(config: &Config) -> Option<ReasoningEffort> {
+    config.model_reasoning_effort.or_else(|| {
+        // ChatGPT auth exposes every preset; only the active model matters here.
+        builtin_model_presets(Some(AuthMode::ChatGPT))
+            .into_iter()
+            .find(|preset| preset.model == config.model)
+            .map(|preset| preset.default_reasoning_effort)
+    })
+}
+
+/// The `approval` summary entry, or `<unknown>` when it is missing.
+pub(crate) fn compose_approval_display(entries: &[(&str, String)]) -> String {
+    entries
+        .iter()
+        .find(|(k, _)| *k == "approval")
+        .map(|(_, v)| v.clone())
+        .unwrap_or_else(|| "<unknown>".to_string())
+}
+
+/// Short name of the sandbox mode, without the writable-root details.
+pub(crate) fn compose_sandbox_display(policy: &SandboxPolicy) -> String {
+    match policy {
+        SandboxPolicy::DangerFullAccess => "danger-full-access".to_string(),
+        SandboxPolicy::ReadOnly => "read-only".to_string(),
+        SandboxPolicy::WorkspaceWrite { .. } => "workspace-write".to_string(),
+    }
 }
 
 pub(crate) fn compose_agents_summary(config: &Config) -> String {
-    match discover_project_doc_paths(config) {
-        Ok(paths) => {
-            let mut rels: Vec<String> = Vec::new();
-            for p in paths {
-                let file_name = p
-                    .file_name()
-                    .map(|name| name.to_string_lossy().to_string())
-                    .unwrap_or_else(|| "<unknown>".to_string());
-                let display = if let Some(parent) = p.parent() {
-                    if parent == config.cwd {
-                        file_name.clone()
-                    } else {
-                        let mut cur = config.cwd.as_path();
-                        let mut ups = 0usize;
-                        let mut reached = false;
-                        while let Some(c) = cur.parent() {
-                            if cur == parent {
-                                reached = true;
-                                break;
-                            }
-                            cur = c;
-                            ups += 1;
-                        }
-                        if reached {
-                            let up = format!("..{}", std::path::MAIN_SEPARATOR);
-                            format!("{}{}", up.repeat(ups), file_name)
-                        } else if let Ok(stripped) = p.strip_prefix(&config.cwd) {
-                            normalize_agents_display_path(stripped)
-                        } else {
-                            normalize_agents_display_path(&p)
-                        }
-                    }
-                } else {
-                    normalize_agents_display_path(&p)
-                };
-                rels.push(display);
-            }
-            if rels.is_empty() {
-                "<none>".to_string()
-            } else {
-                rels.join(", ")
+    let rels: Vec<String> = discover_project_doc_paths(config)
+        .unwrap_or_default()
+        .iter()
+        .map(|p| agents_display_path(p, &config.cwd))
+        .collect();
+    if rels.is_empty() {
+        config
+            .tui_agents_md_empty_message
+            .clone()
+            .unwrap_or_else(|| DEFAULT_AGENTS_EMPTY_MESSAGE.to_string())
+    } else {
+        rels.join(", ")
+    }
+}
+
+/// How a project doc is listed in the status card: its file name when it sits
+/// in `cwd`, `../`-prefixed when it sits in an ancestor of `cwd`, relative to
+/// `cwd` when below it, and the full path otherwise.
+pub(crate) fn agents_display_path(doc: &Path, cwd: &Path) -> String {
+    let file_name = doc
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "<unknown>".to_string());
+    let Some(parent) = doc.parent() else {
+        return normalize_agents_display_path(doc);
+    };
+    if parent == cwd {
+        return file_name;
+    }
+
+    let mut cur = cwd;
+    let mut ups = 0usize;
+    let mut reached = false;
+    while let Some(c) = cur.parent() {
+        if cur == parent {
+            reached = true;
+            break;
+        }
+        cur = c;
+        ups += 1;
+    }
+    if reached {
+        let up = format!("..{}", std::path::MAIN_SEPARATOR);
+        format!("{}{}", up.repeat(ups), file_name)
+    } else if let Ok(stripped) = doc.strip_prefix(cwd) {
+        normalize_agents_display_path(stripped)
+    } else {
+        normalize_agents_display_path(doc)
+    }
+}
+
+/// Connection state reported for a configured MCP server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct McpServerStatus {
+    pub name: String,
+    pub connected: bool,
+}
+
+/// Summarizes the enabled MCP servers from `config` that `statuses` reports as
+/// connected, e.g. `3 connected: a, b, c`. Lists at most
+/// `MAX_MCP_SUMMARY_NAMES` names before collapsing the rest into `+N more`.
+pub(crate) fn compose_mcp_summary(config: &Config, statuses: &[McpServerStatus]) -> String {
+    let mut connected: Vec<&str> = statuses
+        .iter()
+        .filter(|status| status.connected)
+        .filter(|status| {
+            config
+                .mcp_servers
+                .get(&status.name)
+                .is_some_and(|server| server.enabled)
+        })
+        .map(|status| status.name.as_str())
+        .collect();
+    connected.sort_unstable();
+    connected.dedup();
+
+    if connected.is_empty() {
+        return MCP_SUMMARY_EMPTY_MESSAGE.to_string();
+    }
+
+    let count = connected.len();
+    let mut names = connected
+        .iter()
+        .take(MAX_MCP_SUMMARY_NAMES)
+        .copied()
+        .collect::<Vec<_>>()
+        .join(", ");
+    if count > MAX_MCP_SUMMARY_NAMES {
+        names.push_str(&format!(", +{} more", count - MAX_MCP_SUMMARY_NAMES));
+    }
+    format!("{count} connected: {names}")
+}
+
+/// Git context for the status panel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct GitSummary {
+    /// Checked-out branch, or `None` when `HEAD` is detached.
+    pub branch: Option<String>,
+    /// Commits ahead of the upstream; 0 without an upstream.
+    pub ahead: i64,
+    /// Commits behind the upstream; 0 without an upstream.
+    pub behind: i64,
+    /// Whether tracked files have staged or unstaged changes.
+    pub dirty: bool,
+}
+
+/// Branch, upstream divergence and dirty state for the repository containing
+/// `cwd`, or `None` when `cwd` is not inside a git work tree. Untracked files
+/// and submodules are not scanned so this stays fast on large repositories.
+/// Runs `git` synchronously; call it off the UI thread.
+pub(crate) fn compose_git_summary(cwd: &Path) -> Option<GitSummary> {
+    let output = std::process::Command::new("git")
+        .args([
+            "status",
+            "--porcelain=v2",
+            "--branch",
+            "--untracked-files=no",
+            "--ignore-submodules",
+        ])
+        .current_dir(cwd)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(parse_porcelain_v2_status(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
+}
+
+/// Parses `git status --porcelain=v2 --branch` output.
+pub(crate) fn parse_porcelain_v2_status(status: &str) -> GitSummary {
+    let mut summary = GitSummary {
+        branch: None,
+        ahead: 0,
+        behind: 0,
+        dirty: false,
+    };
+    for line in status.lines() {
+        if let Some(head) = line.strip_prefix("# branch.head ") {
+            summary.branch = (head != "(detached)").then(|| head.to_string());
+        } else if let Some(ab) = line.strip_prefix("# branch.ab ") {
+            for part in ab.split_whitespace() {
+                if let Some(ahead) = part.strip_prefix('+') {
+                    summary.ahead = ahead.parse().unwrap_or(0);
+                } else if let Some(behind) = part.strip_prefix('-') {
+                    summary.behind = behind.parse().unwrap_or(0);
+                }
             }
+        } else if !line.starts_with('#') && !line.is_empty() {
+            summary.dirty = true;
         }
-        Err(_) => "<none>".to_string(),
+    }
+    summary
+}
+
+/// Card value for a [`GitSummary`], e.g. `main (ahead 1, behind 2, dirty)`.
+/// A clean branch in sync with its upstream is just the branch name.
+pub(crate) fn format_git_summary(summary: &GitSummary) -> String {
+    let branch = summary.branch.as_deref().unwrap_or("detached HEAD");
+    let mut details = Vec::new();
+    if summary.ahead > 0 {
+        details.push(format!("ahead {}", summary.ahead));
+    }
+    if summary.behind > 0 {
+        details.push(format!("behind {}", summary.behind));
+    }
+    if summary.dirty {
+        details.push("dirty".to_string());
+    }
+    if details.is_empty() {
+        branch.to_string()
+    } else {
+        format!("{branch} ({})", details.join(", "))
     }
 }
 
+/// Account shown in the status card: the active entry of
+/// [`compose_account_displays`].
 pub(crate) fn compose_account_display(config: &Config) -> Option<StatusAccountDisplay> {
-    let auth =
-        load_auth_dot_json(&config.codex_home, config.cli_auth_credentials_store_mode).ok()??;
+    compose_account_displays(config)
+        .into_iter()
+        .find(|entry| entry.active)
+        .map(|entry| entry.account)
+}
 
+/// Every identity configured in the stored credentials, active one first. A
+/// stored API key takes precedence over a ChatGPT login, matching
+/// `codex_core::auth::load_auth`. Missing credentials yield no entries;
+/// credentials that exist but fail to load yield a single
+/// [`StatusAccountDisplay::Unknown`].
+pub(crate) fn compose_account_displays(config: &Config) -> Vec<StatusAccountEntry> {
+    let auth = match load_auth_dot_json(&config.codex_home, config.cli_auth_credentials_store_mode)
+    {
+        Ok(Some(auth)) => auth,
+        Ok(None) => return Vec::new(),
+        Err(err) => {
+            let reason = if err.kind() == std::io::ErrorKind::InvalidData {
+                "credentials could not be parsed"
+            } else {
+                "credentials could not be read"
+            };
+            return vec![StatusAccountEntry {
+                account: StatusAccountDisplay::Unknown {
+                    reason: reason.to_string(),
+                },
+                active: true,
+            }];
+        }
+    };
+
+    let mut accounts = Vec::new();
+    if auth.openai_api_key.is_some() {
+        accounts.push(StatusAccountDisplay::ApiKey);
+    }
     if let Some(tokens) = auth.tokens.as_ref() {
         let info = &tokens.id_token;
         let email = info.email.clone();
-        let plan = info.get_chatgpt_plan_type().as_deref().map(title_case);
-        return Some(StatusAccountDisplay::ChatGpt { email, plan });
-    }
-
-    if let Some(key) = auth.openai_api_key
-        && !key.is_empty()
-    {
-        return Some(StatusAccountDisplay::ApiKey);
+        let plan = info
+            .get_chatgpt_plan_type()
+            .as_deref()
+            .map(title_case_words);
+        accounts.push(StatusAccountDisplay::ChatGpt { email, plan });
     }
 
-    None
+    accounts
+        .into_iter()
+        .enumerate()
+        .map(|(idx, account)| StatusAccountEntry {
+            account,
+            active: idx == 0,
+        })
+        .collect()
 }
 
 pub(crate) fn format_tokens_compact(value: i64) -> String {
@@ -143,6 +433,20 @@ pub(crate) fn format_tokens_compact(value: i64) -> String {
     format!("{formatted}{suffix}")
 }
 
+/// Formats usage against a limit as compact counts plus the share used, e.g.
+/// `128K / 200K (64%)`. A zero (or negative) `limit` yields just the used count.
+pub(crate) fn format_usage_line(used: i64, limit: i64) -> String {
+    let used_fmt = format_tokens_compact(used);
+    if limit <= 0 {
+        return used_fmt;
+    }
+    let percent = (used.max(0) as f64 / limit as f64) * 100.0;
+    format!(
+        "{used_fmt} / {} ({percent:.0}%)",
+        format_tokens_compact(limit)
+    )
+}
+
 pub(crate) fn format_directory_display(directory: &Path, max_width: Option<usize>) -> String {
     let formatted = if let Some(rel) = relativize_to_home(directory) {
         if rel.as_os_str().is_empty() {
@@ -154,6 +458,72 @@ pub(crate) fn format_directory_display(directory: &Path, max_width: Option<usize
         directory.display().to_string()
     };
 
+    fit_path_display(formatted, max_width)
+}
+
+/// Like [`format_directory_display`], but when `directory` is inside
+/// `git_root` renders it as `<reponame>/<subpath>`.
+pub(crate) fn format_directory_display_git_relative(
+    directory: &Path,
+    git_root: &Path,
+    max_width: Option<usize>,
+) -> String {
+    let (Ok(rel), Some(repo_name)) = (directory.strip_prefix(git_root), git_root.file_name())
+    else {
+        return format_directory_display(directory, max_width);
+    };
+    let repo_name = repo_name.to_string_lossy();
+    let formatted = if rel.as_os_str().is_empty() {
+        repo_name.into_owned()
+    } else {
+        format!("{repo_name}{}{}", std::path::MAIN_SEPARATOR, rel.display())
+    };
+
+    fit_path_display(formatted, max_width)
+}
+
+/// Separator between sections of [`compose_compact_status_line`].
+const COMPACT_STATUS_SEPARATOR: &str = " · ";
+/// Narrowest directory worth showing before it is dropped entirely.
+const COMPACT_STATUS_MIN_DIRECTORY_WIDTH: usize = 10;
+
+/// One-line `model · directory · NN% used` summary fitted into `width`
+/// columns. When space runs out the directory is shortened and then dropped,
+/// then the usage percentage is dropped, and finally the model name itself is
+/// truncated. `urgent_used_percent` is the most urgent rate-limit window's
+/// usage; `None` omits that section.
+pub(crate) fn compose_compact_status_line(
+    model: &str,
+    directory: &Path,
+    urgent_used_percent: Option<f64>,
+    width: usize,
+) -> String {
+    let usage = urgent_used_percent.map(|percent| format!("{percent:.0}% used"));
+    let join = |sections: &[&str]| sections.join(COMPACT_STATUS_SEPARATOR);
+
+    let mut fixed: Vec<&str> = vec![model];
+    if let Some(usage) = usage.as_deref() {
+        fixed.push(usage);
+    }
+    let fixed_width = UnicodeWidthStr::width(join(&fixed).as_str());
+    let separator_width = UnicodeWidthStr::width(COMPACT_STATUS_SEPARATOR);
+    let directory_budget = width.saturating_sub(fixed_width + separator_width);
+    if directory_budget >= COMPACT_STATUS_MIN_DIRECTORY_WIDTH {
+        let directory = format_directory_display(directory, Some(directory_budget));
+        let mut sections = vec![model, directory.as_str()];
+        if let Some(usage) = usage.as_deref() {
+            sections.push(usage);
+        }
+        return join(&sections);
+    }
+    if fixed_width <= width {
+        return join(&fixed);
+    }
+
+    text_formatting::truncate_text(model, width)
+}
+
+fn fit_path_display(formatted: String, max_width: Option<usize>) -> String {
     if let Some(max_width) = max_width {
         if max_width == 0 {
             return String::new();
@@ -166,6 +536,94 @@ pub(crate) fn format_directory_display(directory: &Path, max_width: Option<usize
     formatted
 }
 
+/// Estimated spend for the given token counts at `pricing`, formatted as US
+/// dollars with two decimals (e.g. `$1.23`). Non-zero amounts under a cent
+/// show as `<$0.01`.
+pub(crate) fn estimate_cost(
+    input_tokens: i64,
+    output_tokens: i64,
+    pricing: &ModelPricing,
+) -> String {
+    let cost = (input_tokens.max(0) as f64 * pricing.input_per_million_tokens
+        + output_tokens.max(0) as f64 * pricing.output_per_million_tokens)
+        / 1_000_000.0;
+    if cost > 0.0 && cost < 0.01 {
+        "<$0.01".to_string()
+    } else {
+        format!("${cost:.2}")
+    }
+}
+
+/// Compact elapsed session time, e.g. `3m`, `1h 5m` or `2d 4h`. Rounds down
+/// to whole minutes, shows at most the two largest units (days are the
+/// largest) and omits a trailing zero unit. Under a minute shows `<1m`.
+pub(crate) fn format_session_elapsed(start: Instant, now: Instant) -> String {
+    let total_minutes = now.saturating_duration_since(start).as_secs() / 60;
+    let days = total_minutes / (24 * 60);
+    let hours = (total_minutes / 60) % 24;
+    let minutes = total_minutes % 60;
+
+    match (days, hours, minutes) {
+        (0, 0, 0) => "<1m".to_string(),
+        (0, 0, m) => format!("{m}m"),
+        (0, h, 0) => format!("{h}h"),
+        (0, h, m) => format!("{h}h {m}m"),
+        (d, 0, _) => format!("{d}d"),
+        (d, h, _) => format!("{d}d {h}h"),
+    }
+}
+
+/// Fit an email address into `max_width` columns by replacing its middle with
+/// an ellipsis. The tail keeps at least the top-level domain (e.g. `.com`)
+/// so the address stays recognizable.
+pub(crate) fn format_email_display(email: &str, max_width: Option<usize>) -> String {
+    let Some(max_width) = max_width else {
+        return email.to_string();
+    };
+    if UnicodeWidthStr::width(email) <= max_width {
+        return email.to_string();
+    }
+    if max_width <= 1 {
+        return "…".repeat(max_width);
+    }
+
+    let budget = max_width - 1;
+    let tld_width = email
+        .rfind('.')
+        .map(|idx| UnicodeWidthStr::width(&email[idx..]))
+        .unwrap_or(0);
+    let tail_budget = (budget / 2).max(tld_width).min(budget);
+    let head_budget = budget - tail_budget;
+
+    let mut head = String::new();
+    let mut head_width = 0;
+    for ch in email.chars() {
+        let ch_width = UnicodeWidthChar::width(ch).unwrap_or(0);
+        if head_width + ch_width > head_budget {
+            break;
+        }
+        head_width += ch_width;
+        head.push(ch);
+    }
+
+    let mut tail: Vec<char> = Vec::new();
+    let mut tail_width = 0;
+    for ch in email.chars().rev() {
+        let ch_width = UnicodeWidthChar::width(ch).unwrap_or(0);
+        if tail_width + ch_width > tail_budget {
+            break;
+        }
+        tail_width += ch_width;
+        tail.push(ch);
+    }
+    tail.reverse();
+
+    let mut truncated = head;
+    truncated.push('…');
+    truncated.extend(tail);
+    truncated
+}
+
 pub(crate) fn format_reset_timestamp(dt: DateTime<Local>, captured_at: DateTime<Local>) -> String {
     let time = dt.format("%H:%M").to_string();
     if dt.date_naive() == captured_at.date_naive() {
@@ -187,3 +645,12 @@ pub(crate) fn title_case(s: &str) -> String {
     let rest: String = chars.as_str().to_ascii_lowercase();
     first.to_uppercase().collect::<String>() + &rest
 }
+
+/// Applies [`title_case`] to each whitespace-separated word, so "team pro"
+/// becomes "Team Pro". Runs of whitespace collapse to a single space.
+pub(crate) fn title_case_words(s: &str) -> String {
+    s.split_whitespace()
+        .map(title_case)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
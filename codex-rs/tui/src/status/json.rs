@@ -0,0 +1,124 @@
+use chrono::DateTime;
+use chrono::Utc;
+use codex_common::create_config_summary_entries;
+use codex_core::config::Config;
+use codex_core::protocol::TokenUsage;
+use codex_protocol::ConversationId;
+use ratatui::text::Line;
+use serde::Serialize;
+use std::path::PathBuf;
+
+use super::account::StatusAccountDisplay;
+use super::helpers::compose_account_display;
+use super::helpers::compose_agents_summary;
+use super::helpers::compose_approval_display;
+use super::helpers::compose_model_display;
+use super::helpers::compose_sandbox_display;
+use super::rate_limits::RateLimitSnapshotDisplay;
+use super::rate_limits::RateLimitWindowDisplay;
+use crate::history_cell::PlainHistoryCell;
+
+/// Machine-readable form of the `/status` card for tools outside the TUI.
+/// Field names are part of the output format; unknown values serialize as
+/// `null`.
+#[derive(Debug, Serialize)]
+pub(crate) struct StatusJson {
+    pub model: String,
+    pub model_details: Vec<String>,
+    pub directory: PathBuf,
+    pub approval: String,
+    pub sandbox: String,
+    pub agents_summary: String,
+    pub account: Option<StatusAccountDisplay>,
+    pub session_id: Option<String>,
+    pub token_usage: StatusJsonTokenUsage,
+    pub rate_limits: Option<StatusJsonRateLimits>,
+}
+
+/// Session totals, counted the same way as the card's `Token usage` row.
+#[derive(Debug, Serialize)]
+pub(crate) struct StatusJsonTokenUsage {
+    pub total: i64,
+    pub input: i64,
+    pub output: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct StatusJsonRateLimits {
+    pub captured_at: DateTime<Utc>,
+    pub primary: Option<StatusJsonRateLimitWindow>,
+    pub secondary: Option<StatusJsonRateLimitWindow>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct StatusJsonRateLimitWindow {
+    pub used_percent: f64,
+    pub resets_at: Option<String>,
+    pub window_minutes: Option<i64>,
+    /// Seconds from `captured_at` until the window resets.
+    pub resets_in_seconds: Option<u64>,
+}
+
+impl From<&RateLimitWindowDisplay> for StatusJsonRateLimitWindow {
+    fn from(window: &RateLimitWindowDisplay) -> Self {
+        Self {
+            used_percent: window.used_percent,
+            resets_at: window.resets_at.clone(),
+            window_minutes: window.window_minutes,
+            resets_in_seconds: window.reset_after.map(|delay| delay.as_secs()),
+        }
+    }
+}
+
+pub(crate) fn status_json(
+    config: &Config,
+    total_usage: &TokenUsage,
+    context_usage: Option<&TokenUsage>,
+    session_id: &Option<ConversationId>,
+    rate_limits: Option<&RateLimitSnapshotDisplay>,
+) -> serde_json::Value {
+    let config_entries = create_config_summary_entries(config);
+    let (model, model_details) = compose_model_display(config, &config_entries, context_usage);
+    let status = StatusJson {
+        model,
+        model_details,
+        directory: config.cwd.clone(),
+        approval: compose_approval_display(&config_entries),
+        sandbox: compose_sandbox_display(&config.sandbox_policy),
+        agents_summary: compose_agents_summary(config),
+        account: compose_account_display(config),
+        session_id: session_id.as_ref().map(std::string::ToString::to_string),
+        token_usage: StatusJsonTokenUsage {
+            total: total_usage.blended_total(),
+            input: total_usage.non_cached_input(),
+            output: total_usage.output_tokens,
+        },
+        rate_limits: rate_limits.map(|snapshot| StatusJsonRateLimits {
+            captured_at: snapshot.captured_at.with_timezone(&Utc),
+            primary: snapshot.primary.as_ref().map(Into::into),
+            secondary: snapshot.secondary.as_ref().map(Into::into),
+        }),
+    };
+
+    #[expect(clippy::expect_used)]
+    serde_json::to_value(status).expect("serialize StatusJson")
+}
+
+/// History cell for `/status-json`: the pretty-printed [`status_json`] value,
+/// one JSON line per rendered line so it can be copied verbatim.
+pub(crate) fn new_status_json_output(
+    config: &Config,
+    total_usage: &TokenUsage,
+    context_usage: Option<&TokenUsage>,
+    session_id: &Option<ConversationId>,
+    rate_limits: Option<&RateLimitSnapshotDisplay>,
+) -> PlainHistoryCell {
+    let value = status_json(config, total_usage, context_usage, session_id, rate_limits);
+    #[expect(clippy::expect_used)]
+    let pretty = serde_json::to_string_pretty(&value).expect("serialize status JSON");
+    let lines = pretty
+        .lines()
+        .map(|line| Line::from(line.to_string()))
+        .collect();
+    PlainHistoryCell::new(lines)
+}
@@ -2,10 +2,20 @@ mod account;
 mod card;
 mod format;
 mod helpers;
+mod json;
 mod rate_limits;
+mod sparkline;
 
+pub(crate) use card::StatusSessionDetails;
 pub(crate) use card::new_status_output;
+pub(crate) use helpers::GitSummary;
+pub(crate) use helpers::McpServerStatus;
+pub(crate) use helpers::compose_git_summary;
+pub(crate) use json::new_status_json_output;
 pub(crate) use rate_limits::RateLimitSnapshotDisplay;
+pub(crate) use rate_limits::RateLimitWindowKind;
+pub(crate) use rate_limits::describe_rate_limit_transition;
+pub(crate) use rate_limits::detect_rate_limit_reset;
 pub(crate) use rate_limits::rate_limit_snapshot_display;
 
 #[cfg(test)]
@@ -5,8 +5,12 @@ use chrono::DateTime;
 use chrono::Duration as ChronoDuration;
 use chrono::Local;
 use chrono::Utc;
+use codex_core::config::types::LimitGlyphs;
 use codex_core::protocol::RateLimitSnapshot;
 use codex_core::protocol::RateLimitWindow;
+use serde::Deserialize;
+use serde::Serialize;
+use std::time::Duration;
 
 const STATUS_LIMIT_BAR_SEGMENTS: usize = 20;
 const STATUS_LIMIT_BAR_FILLED: &str = "█";
@@ -28,36 +32,262 @@ pub(crate) enum StatusRateLimitData {
 
 pub(crate) const RATE_LIMIT_STALE_THRESHOLD_MINUTES: i64 = 15;
 
-#[derive(Debug, Clone)]
+/// Usage moves of at least this many percentage points are reported as
+/// significant by [`describe_rate_limit_transition`].
+const RATE_LIMIT_SIGNIFICANT_CHANGE_PERCENT: f64 = 5.0;
+
+/// A window whose usage falls by more than this many percentage points is
+/// treated as freshly reset by [`detect_rate_limit_reset`].
+const RATE_LIMIT_RESET_DROP_PERCENT: f64 = 50.0;
+
+/// Which of a snapshot's two rate-limit windows something refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RateLimitWindowKind {
+    Primary,
+    Secondary,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub(crate) struct RateLimitWindowDisplay {
     pub used_percent: f64,
     pub resets_at: Option<String>,
     pub window_minutes: Option<i64>,
+    /// Time from the snapshot capture until the window resets. Not
+    /// serialized here, so deserialized windows have no known reset delay;
+    /// `/status-json` reports it as `resets_in_seconds`.
+    #[serde(skip)]
+    pub reset_after: Option<Duration>,
 }
 
 impl RateLimitWindowDisplay {
     fn from_window(window: &RateLimitWindow, captured_at: DateTime<Local>) -> Self {
-        let resets_at = window
+        let reset_time = window
             .resets_at
             .and_then(|seconds| DateTime::<Utc>::from_timestamp(seconds, 0))
-            .map(|dt| dt.with_timezone(&Local))
-            .map(|dt| format_reset_timestamp(dt, captured_at));
+            .map(|dt| dt.with_timezone(&Local));
+        let resets_at = reset_time.map(|dt| format_reset_timestamp(dt, captured_at));
+        let reset_after = reset_time.and_then(|dt| (dt - captured_at).to_std().ok());
 
         Self {
             used_percent: window.used_percent,
             resets_at,
             window_minutes: window.window_minutes,
+            reset_after,
         }
     }
+
+    /// How long after the snapshot was captured this window resets, for
+    /// comparing windows by reset time. `None` when the reset time is unknown,
+    /// invalid, or already in the past.
+    pub(crate) fn reset_in(&self) -> Option<Duration> {
+        self.reset_after
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub(crate) struct RateLimitSnapshotDisplay {
     pub captured_at: DateTime<Local>,
     pub primary: Option<RateLimitWindowDisplay>,
     pub secondary: Option<RateLimitWindowDisplay>,
 }
 
+impl RateLimitSnapshotDisplay {
+    /// Start building a snapshot captured at `captured_at` with no windows.
+    #[cfg(test)]
+    pub(crate) fn builder(captured_at: DateTime<Local>) -> RateLimitSnapshotDisplayBuilder {
+        RateLimitSnapshotDisplayBuilder {
+            snapshot: RateLimitSnapshotDisplay {
+                captured_at,
+                primary: None,
+                secondary: None,
+            },
+        }
+    }
+
+    /// Overlay a partial `update`: windows present in `update` replace ours,
+    /// windows it omits are kept. The result takes `update`'s capture time.
+    pub(crate) fn merge(&self, update: &RateLimitSnapshotDisplay) -> RateLimitSnapshotDisplay {
+        RateLimitSnapshotDisplay {
+            captured_at: update.captured_at,
+            primary: update.primary.clone().or_else(|| self.primary.clone()),
+            secondary: update.secondary.clone().or_else(|| self.secondary.clone()),
+        }
+    }
+
+    /// The window closest to its limit. When both windows are equally used,
+    /// the one that resets sooner wins; if either reset time is unknown the
+    /// primary window wins.
+    pub(crate) fn most_urgent_window(&self) -> Option<&RateLimitWindowDisplay> {
+        match (self.primary.as_ref(), self.secondary.as_ref()) {
+            (Some(primary), Some(secondary)) => {
+                let secondary_more_urgent = if primary.used_percent == secondary.used_percent {
+                    matches!(
+                        (primary.reset_in(), secondary.reset_in()),
+                        (Some(primary_reset), Some(secondary_reset))
+                            if secondary_reset < primary_reset
+                    )
+                } else {
+                    secondary.used_percent > primary.used_percent
+                };
+                Some(if secondary_more_urgent {
+                    secondary
+                } else {
+                    primary
+                })
+            }
+            (primary, secondary) => primary.or(secondary),
+        }
+    }
+}
+
+/// Builder for [`RateLimitSnapshotDisplay`]; see
+/// [`RateLimitSnapshotDisplay::builder`]. Windows built this way have no known
+/// reset delay, so it is only used to build test fixtures.
+#[cfg(test)]
+pub(crate) struct RateLimitSnapshotDisplayBuilder {
+    snapshot: RateLimitSnapshotDisplay,
+}
+
+#[cfg(test)]
+impl RateLimitSnapshotDisplayBuilder {
+    /// Set the primary window's usage, formatted reset time, and length.
+    pub(crate) fn primary(
+        mut self,
+        used_percent: f64,
+        resets_at: Option<String>,
+        window_minutes: Option<i64>,
+    ) -> Self {
+        self.snapshot.primary = Some(RateLimitWindowDisplay {
+            used_percent,
+            resets_at,
+            window_minutes,
+            reset_after: None,
+        });
+        self
+    }
+
+    /// Set the secondary window's usage, formatted reset time, and length.
+    pub(crate) fn secondary(
+        mut self,
+        used_percent: f64,
+        resets_at: Option<String>,
+        window_minutes: Option<i64>,
+    ) -> Self {
+        self.snapshot.secondary = Some(RateLimitWindowDisplay {
+            used_percent,
+            resets_at,
+            window_minutes,
+            reset_after: None,
+        });
+        self
+    }
+
+    pub(crate) fn build(self) -> RateLimitSnapshotDisplay {
+        self.snapshot
+    }
+}
+
+/// The first window (primary before secondary) that looks like it just reset
+/// between `old` and `new`: its usage dropped by more than 50 percentage
+/// points and, when both reset times are known, its reset moved later.
+pub(crate) fn detect_rate_limit_reset(
+    old: &RateLimitSnapshotDisplay,
+    new: &RateLimitSnapshotDisplay,
+) -> Option<RateLimitWindowKind> {
+    let window_reset = |old_window: &Option<RateLimitWindowDisplay>,
+                        new_window: &Option<RateLimitWindowDisplay>| {
+        let (Some(old_window), Some(new_window)) = (old_window, new_window) else {
+            return false;
+        };
+        if old_window.used_percent - new_window.used_percent <= RATE_LIMIT_RESET_DROP_PERCENT {
+            return false;
+        }
+        match (
+            reset_instant(old.captured_at, old_window),
+            reset_instant(new.captured_at, new_window),
+        ) {
+            (Some(old_reset), Some(new_reset)) => new_reset > old_reset,
+            _ => true,
+        }
+    };
+
+    if window_reset(&old.primary, &new.primary) {
+        Some(RateLimitWindowKind::Primary)
+    } else if window_reset(&old.secondary, &new.secondary) {
+        Some(RateLimitWindowKind::Secondary)
+    } else {
+        None
+    }
+}
+
+fn reset_instant(
+    captured_at: DateTime<Local>,
+    window: &RateLimitWindowDisplay,
+) -> Option<DateTime<Local>> {
+    let reset_after = ChronoDuration::from_std(window.reset_after?).ok()?;
+    captured_at.checked_add_signed(reset_after)
+}
+
+/// Explain how the stored rate-limit snapshot changed, for debug logging
+/// (e.g. "primary 72% -> 79% significant change; secondary unchanged at 20%").
+pub(crate) fn describe_rate_limit_transition(
+    old: Option<&RateLimitSnapshotDisplay>,
+    new: Option<&RateLimitSnapshotDisplay>,
+) -> String {
+    match (old, new) {
+        (None, None) => "no snapshot".to_string(),
+        (Some(_), None) => "cleared".to_string(),
+        (None, Some(new)) => {
+            let windows = [("primary", &new.primary), ("secondary", &new.secondary)]
+                .into_iter()
+                .filter_map(|(label, window)| {
+                    window
+                        .as_ref()
+                        .map(|window| format!("{label} {:.0}%", window.used_percent))
+                })
+                .collect::<Vec<_>>();
+            if windows.is_empty() {
+                "first snapshot: no windows".to_string()
+            } else {
+                format!("first snapshot: {}", windows.join(", "))
+            }
+        }
+        (Some(old), Some(new)) => [
+            describe_window_transition("primary", &old.primary, &new.primary),
+            describe_window_transition("secondary", &old.secondary, &new.secondary),
+        ]
+        .join("; "),
+    }
+}
+
+fn describe_window_transition(
+    label: &str,
+    old: &Option<RateLimitWindowDisplay>,
+    new: &Option<RateLimitWindowDisplay>,
+) -> String {
+    match (old, new) {
+        (None, None) => format!("{label} absent"),
+        (Some(old), None) => format!("{label} {:.0}% -> absent", old.used_percent),
+        (None, Some(new)) => format!("{label} absent -> {:.0}%", new.used_percent),
+        (Some(old), Some(new)) => {
+            let delta = (new.used_percent - old.used_percent).abs();
+            if delta == 0.0 {
+                format!("{label} unchanged at {:.0}%", new.used_percent)
+            } else {
+                let kind = if delta >= RATE_LIMIT_SIGNIFICANT_CHANGE_PERCENT {
+                    "significant"
+                } else {
+                    "minor"
+                };
+                format!(
+                    "{label} {:.0}% -> {:.0}% {kind} change",
+                    old.used_percent, new.used_percent
+                )
+            }
+        }
+    }
+}
+
 pub(crate) fn rate_limit_snapshot_display(
     snapshot: &RateLimitSnapshot,
     captured_at: DateTime<Local>,
@@ -78,6 +308,7 @@ pub(crate) fn rate_limit_snapshot_display(
 pub(crate) fn compose_rate_limit_data(
     snapshot: Option<&RateLimitSnapshotDisplay>,
     now: DateTime<Local>,
+    glyphs: LimitGlyphs,
 ) -> StatusRateLimitData {
     match snapshot {
         Some(snapshot) => {
@@ -88,9 +319,10 @@ pub(crate) fn compose_rate_limit_data(
                     .window_minutes
                     .map(get_limits_duration)
                     .unwrap_or_else(|| "5h".to_string());
+                let glyph = limit_window_glyph(&label, glyphs);
                 let label = capitalize_first(&label);
                 rows.push(StatusRateLimitRow {
-                    label: format!("{label} limit"),
+                    label: format!("{glyph}{label} limit"),
                     percent_used: primary.used_percent,
                     resets_at: primary.resets_at.clone(),
                 });
@@ -101,9 +333,10 @@ pub(crate) fn compose_rate_limit_data(
                     .window_minutes
                     .map(get_limits_duration)
                     .unwrap_or_else(|| "weekly".to_string());
+                let glyph = limit_window_glyph(&label, glyphs);
                 let label = capitalize_first(&label);
                 rows.push(StatusRateLimitRow {
-                    label: format!("{label} limit"),
+                    label: format!("{glyph}{label} limit"),
                     percent_used: secondary.used_percent,
                     resets_at: secondary.resets_at.clone(),
                 });
@@ -136,8 +369,42 @@ pub(crate) fn render_status_limit_progress_bar(percent_remaining: f64) -> String
     )
 }
 
+/// Formats the remaining share of a limit. The last percent before the limit
+/// keeps one decimal (e.g. `0.6% left` at 99.4% used) so it does not round
+/// away; anything under that decimal reads `<0.1% left` rather than `0.0%`,
+/// and only an exhausted limit reads `limit reached`.
 pub(crate) fn format_status_limit_summary(percent_remaining: f64) -> String {
-    format!("{percent_remaining:.0}% left")
+    if percent_remaining <= 0.0 {
+        "limit reached".to_string()
+    } else if percent_remaining < 0.05 {
+        "<0.1% left".to_string()
+    } else if percent_remaining <= 1.0 {
+        format!("{percent_remaining:.1}% left")
+    } else {
+        format!("{percent_remaining:.0}% left")
+    }
+}
+
+/// Formats the reset hint shown after a limit, e.g. `(resets 14:30)`. Returns
+/// an empty string when the reset time is unknown; callers own the separator.
+pub(crate) fn format_reset_suffix(reset: Option<&str>) -> String {
+    reset
+        .map(|reset| format!("(resets {reset})"))
+        .unwrap_or_default()
+}
+
+/// Glyph (with a trailing space) marking a window labelled by
+/// [`get_limits_duration`]: a clock for hour-based windows, a calendar for
+/// weekly and longer ones. Empty for [`LimitGlyphs::None`].
+pub(crate) fn limit_window_glyph(duration_label: &str, glyphs: LimitGlyphs) -> &'static str {
+    let short_window = duration_label.ends_with('h');
+    match (glyphs, short_window) {
+        (LimitGlyphs::None, _) => "",
+        (LimitGlyphs::Unicode, true) => "◷ ",
+        (LimitGlyphs::Unicode, false) => "▦ ",
+        (LimitGlyphs::Ascii, true) => "o ",
+        (LimitGlyphs::Ascii, false) => "# ",
+    }
 }
 
 fn capitalize_first(label: &str) -> String {
@@ -0,0 +1,53 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+use ratatui::widgets::Widget;
+use ratatui::widgets::WidgetRef;
+
+/// Block glyphs from lowest to highest usage.
+const LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// One-row trend of recent usage percentages (0–100), newest on the right.
+/// When the history is wider than the area only the most recent points are
+/// drawn; fewer than two points render nothing.
+pub(crate) struct UsageSparkline<'a> {
+    history: &'a [f64],
+}
+
+impl<'a> UsageSparkline<'a> {
+    pub(crate) fn new(history: &'a [f64]) -> Self {
+        Self { history }
+    }
+
+    /// The trend as a dimmed line at most `width` cells wide, or `None` when
+    /// there is nothing to draw.
+    pub(crate) fn line(&self, width: usize) -> Option<Line<'static>> {
+        if self.history.len() < 2 || width == 0 {
+            return None;
+        }
+        let start = self.history.len().saturating_sub(width);
+        let trend: String = self.history[start..]
+            .iter()
+            .map(|percent| level_for(*percent))
+            .collect();
+        Some(Line::from(trend.dim()))
+    }
+}
+
+impl WidgetRef for UsageSparkline<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+        if let Some(line) = self.line(usize::from(area.width)) {
+            line.render(area, buf);
+        }
+    }
+}
+
+fn level_for(percent: f64) -> char {
+    let fraction = percent.clamp(0.0, 100.0) / 100.0;
+    let index = (fraction * (LEVELS.len() - 1) as f64).round() as usize;
+    LEVELS[index.min(LEVELS.len() - 1)]
+}
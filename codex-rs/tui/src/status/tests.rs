@@ -1,5 +1,42 @@
+use super::StatusSessionDetails;
+use super::account::StatusAccountDisplay;
+use super::account::StatusAccountEntry;
+use super::format::FieldFormatter;
+use super::format::status_max_width;
+use super::helpers::GitSummary;
+use super::helpers::McpServerStatus;
+use super::helpers::SummariesState;
+use super::helpers::agents_display_path;
+use super::helpers::compose_account_display;
+use super::helpers::compose_account_displays;
+use super::helpers::compose_agents_summary;
+use super::helpers::compose_compact_status_line;
+use super::helpers::compose_git_summary;
+use super::helpers::compose_mcp_summary;
+use super::helpers::compose_model_display;
+use super::helpers::compose_model_display_with_summaries;
+use super::helpers::estimate_cost;
+use super::helpers::format_directory_display;
+use super::helpers::format_directory_display_git_relative;
+use super::helpers::format_email_display;
+use super::helpers::format_git_summary;
+use super::helpers::format_session_elapsed;
+use super::helpers::format_usage_line;
+use super::helpers::parse_porcelain_v2_status;
+use super::helpers::title_case;
+use super::helpers::title_case_words;
+use super::json::status_json;
+use super::new_status_json_output;
 use super::new_status_output;
 use super::rate_limit_snapshot_display;
+use super::rate_limits::RateLimitSnapshotDisplay;
+use super::rate_limits::RateLimitWindowDisplay;
+use super::rate_limits::RateLimitWindowKind;
+use super::rate_limits::describe_rate_limit_transition;
+use super::rate_limits::detect_rate_limit_reset;
+use super::rate_limits::format_reset_suffix;
+use super::rate_limits::format_status_limit_summary;
+use super::sparkline::UsageSparkline;
 use crate::history_cell::HistoryCell;
 use chrono::Duration as ChronoDuration;
 use chrono::TimeZone;
@@ -7,16 +44,28 @@ use chrono::Utc;
 use codex_core::config::Config;
 use codex_core::config::ConfigOverrides;
 use codex_core::config::ConfigToml;
+use codex_core::config::types::LimitGlyphs;
+use codex_core::config::types::McpServerConfig;
+use codex_core::config::types::McpServerTransportConfig;
+use codex_core::config::types::ModelPricing;
 use codex_core::protocol::RateLimitSnapshot;
 use codex_core::protocol::RateLimitWindow;
 use codex_core::protocol::SandboxPolicy;
 use codex_core::protocol::TokenUsage;
+use codex_protocol::ConversationId;
 use codex_protocol::config_types::ReasoningEffort;
 use codex_protocol::config_types::ReasoningSummary;
 use insta::assert_snapshot;
+use pretty_assertions::assert_eq;
 use ratatui::prelude::*;
+use ratatui::widgets::WidgetRef;
+use std::collections::HashMap;
+use std::path::Path;
 use std::path::PathBuf;
+use std::time::Duration;
+use std::time::Instant;
 use tempfile::TempDir;
+use unicode_width::UnicodeWidthStr;
 
 fn test_config(temp_home: &TempDir) -> Config {
     Config::load_from_base_config_with_overrides(
@@ -117,6 +166,7 @@ fn status_snapshot_includes_reasoning_details() {
         Some(&usage),
         &None,
         Some(&rate_display),
+        &StatusSessionDetails::default(),
         captured_at,
     );
     let mut rendered_lines = render_lines(&composite.display_lines(80));
@@ -165,6 +215,7 @@ fn status_snapshot_includes_monthly_limit() {
         Some(&usage),
         &None,
         Some(&rate_display),
+        &StatusSessionDetails::default(),
         captured_at,
     );
     let mut rendered_lines = render_lines(&composite.display_lines(80));
@@ -197,7 +248,15 @@ fn status_card_token_usage_excludes_cached_tokens() {
         .single()
         .expect("timestamp");
 
-    let composite = new_status_output(&config, &usage, Some(&usage), &None, None, now);
+    let composite = new_status_output(
+        &config,
+        &usage,
+        Some(&usage),
+        &None,
+        None,
+        &StatusSessionDetails::default(),
+        now,
+    );
     let rendered = render_lines(&composite.display_lines(120));
 
     assert!(
@@ -206,6 +265,45 @@ fn status_card_token_usage_excludes_cached_tokens() {
     );
 }
 
+#[test]
+fn status_card_shows_session_elapsed_after_session_id() {
+    let temp_home = TempDir::new().expect("temp home");
+    let mut config = test_config(&temp_home);
+    config.cwd = PathBuf::from("/workspace/tests");
+    let session_id =
+        ConversationId::from_string("0199a213-81c0-7800-8aa1-bbab2a035a53").expect("session id");
+    let started_at = Instant::now()
+        .checked_sub(Duration::from_secs(65 * 60))
+        .expect("start instant");
+    let now = chrono::Local
+        .with_ymd_and_hms(2024, 1, 1, 0, 0, 0)
+        .single()
+        .expect("timestamp");
+
+    let composite = new_status_output(
+        &config,
+        &TokenUsage::default(),
+        None,
+        &Some(session_id),
+        None,
+        &StatusSessionDetails {
+            started_at: Some(started_at),
+            ..Default::default()
+        },
+        now,
+    );
+    let rendered = render_lines(&composite.display_lines(120));
+
+    let session_line = rendered
+        .iter()
+        .find(|line| line.contains("Session:"))
+        .expect("session row");
+    assert!(
+        session_line.contains("0199a213-81c0-7800-8aa1-bbab2a035a53 (1h 5m)"),
+        "unexpected session row: {session_line}"
+    );
+}
+
 #[test]
 fn status_snapshot_truncates_in_narrow_terminal() {
     let temp_home = TempDir::new().expect("temp home");
@@ -244,6 +342,7 @@ fn status_snapshot_truncates_in_narrow_terminal() {
         Some(&usage),
         &None,
         Some(&rate_display),
+        &StatusSessionDetails::default(),
         captured_at,
     );
     let mut rendered_lines = render_lines(&composite.display_lines(46));
@@ -277,7 +376,15 @@ fn status_snapshot_shows_missing_limits_message() {
         .single()
         .expect("timestamp");
 
-    let composite = new_status_output(&config, &usage, Some(&usage), &None, None, now);
+    let composite = new_status_output(
+        &config,
+        &usage,
+        Some(&usage),
+        &None,
+        None,
+        &StatusSessionDetails::default(),
+        now,
+    );
     let mut rendered_lines = render_lines(&composite.display_lines(80));
     if cfg!(windows) {
         for line in &mut rendered_lines {
@@ -319,6 +426,7 @@ fn status_snapshot_shows_empty_limits_message() {
         Some(&usage),
         &None,
         Some(&rate_display),
+        &StatusSessionDetails::default(),
         captured_at,
     );
     let mut rendered_lines = render_lines(&composite.display_lines(80));
@@ -371,6 +479,7 @@ fn status_snapshot_shows_stale_limits_message() {
         Some(&usage),
         &None,
         Some(&rate_display),
+        &StatusSessionDetails::default(),
         now,
     );
     let mut rendered_lines = render_lines(&composite.display_lines(80));
@@ -409,7 +518,15 @@ fn status_context_window_uses_last_usage() {
         .single()
         .expect("timestamp");
 
-    let composite = new_status_output(&config, &total_usage, Some(&last_usage), &None, None, now);
+    let composite = new_status_output(
+        &config,
+        &total_usage,
+        Some(&last_usage),
+        &None,
+        None,
+        &StatusSessionDetails::default(),
+        now,
+    );
     let rendered_lines = render_lines(&composite.display_lines(80));
     let context_line = rendered_lines
         .into_iter()
@@ -425,3 +542,1567 @@ fn status_context_window_uses_last_usage() {
         "context line should not use total aggregated tokens, got: {context_line}"
     );
 }
+
+#[test]
+fn status_max_width_counts_wide_characters() {
+    // The CJK line has fewer chars than the ASCII one but is wider on screen.
+    let lines = vec![
+        "Model: gpt-5".to_string(),
+        "目录: 项目目录".to_string(),
+        "ok".to_string(),
+    ];
+
+    assert_eq!(status_max_width(&lines), 14);
+}
+
+#[test]
+fn status_max_width_empty_is_zero() {
+    assert_eq!(status_max_width(Vec::<String>::new()), 0);
+}
+
+#[test]
+fn agents_summary_defaults_to_none_without_docs() {
+    let temp_home = TempDir::new().expect("temp home");
+    let project = TempDir::new().expect("project dir");
+    let mut config = test_config(&temp_home);
+    config.cwd = project.path().to_path_buf();
+
+    assert_eq!(compose_agents_summary(&config), "<none>");
+}
+
+#[test]
+fn agents_summary_uses_configured_empty_message() {
+    let temp_home = TempDir::new().expect("temp home");
+    let project = TempDir::new().expect("project dir");
+    let mut config = test_config(&temp_home);
+    config.cwd = project.path().to_path_buf();
+    config.tui_agents_md_empty_message = Some("no AGENTS.md — run /init".to_string());
+
+    assert_eq!(compose_agents_summary(&config), "no AGENTS.md — run /init");
+}
+
+#[cfg(unix)]
+#[test]
+fn agents_summary_uses_configured_empty_message_when_discovery_fails() {
+    let temp_home = TempDir::new().expect("temp home");
+    let project = TempDir::new().expect("project dir");
+    // A file as cwd makes probing `<cwd>/.git` fail with ENOTDIR.
+    let not_a_dir = project.path().join("file");
+    std::fs::write(&not_a_dir, "").expect("write file");
+    let mut config = test_config(&temp_home);
+    config.cwd = not_a_dir;
+    config.tui_agents_md_empty_message = Some("no AGENTS.md — run /init".to_string());
+
+    assert_eq!(compose_agents_summary(&config), "no AGENTS.md — run /init");
+}
+
+#[test]
+fn reset_suffix_formats_known_reset() {
+    assert_eq!(format_reset_suffix(Some("14:30")), "(resets 14:30)");
+}
+
+#[test]
+fn limit_summary_keeps_a_decimal_near_the_limit() {
+    let summaries: Vec<String> = [99.4, 99.9, 99.96, 100.0, 42.4]
+        .into_iter()
+        .map(|used: f64| format_status_limit_summary((100.0 - used).clamp(0.0, 100.0)))
+        .collect();
+
+    assert_eq!(
+        summaries,
+        vec![
+            "0.6% left".to_string(),
+            "0.1% left".to_string(),
+            "<0.1% left".to_string(),
+            "limit reached".to_string(),
+            "58% left".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn reset_suffix_is_empty_without_reset() {
+    assert_eq!(format_reset_suffix(None), "");
+}
+
+#[test]
+fn status_limit_rows_use_reset_suffix() {
+    let temp_home = TempDir::new().expect("temp home");
+    let config = test_config(&temp_home);
+    let usage = TokenUsage::default();
+    let captured_at = chrono::Local
+        .with_ymd_and_hms(2024, 1, 2, 3, 4, 5)
+        .single()
+        .expect("timestamp");
+    let snapshot = RateLimitSnapshot {
+        primary: Some(RateLimitWindow {
+            used_percent: 30.0,
+            window_minutes: Some(300),
+            resets_at: Some(reset_at_from(&captured_at, 600)),
+        }),
+        secondary: Some(RateLimitWindow {
+            used_percent: 10.0,
+            window_minutes: Some(10_080),
+            resets_at: None,
+        }),
+    };
+    let rate_display = rate_limit_snapshot_display(&snapshot, captured_at);
+    let primary_reset = rate_display
+        .primary
+        .as_ref()
+        .and_then(|window| window.resets_at.as_deref());
+
+    let composite = new_status_output(
+        &config,
+        &usage,
+        Some(&usage),
+        &None,
+        Some(&rate_display),
+        &StatusSessionDetails::default(),
+        captured_at,
+    );
+    let rendered = render_lines(&composite.display_lines(120));
+
+    let limit_line = |label: &str| {
+        rendered
+            .iter()
+            .find(|line| line.contains(label))
+            .map(|line| line.trim_end_matches([' ', '│']).to_string())
+            .expect("limit line")
+    };
+    let expected_suffix = format_reset_suffix(primary_reset);
+    assert!(
+        limit_line("5h limit").ends_with(&format!(" {expected_suffix}")),
+        "primary line should end with {expected_suffix:?}"
+    );
+    assert!(limit_line("Weekly limit").ends_with("% left"));
+}
+
+#[test]
+fn status_limit_rows_prefix_window_glyphs() {
+    let temp_home = TempDir::new().expect("temp home");
+    let mut config = test_config(&temp_home);
+    let usage = TokenUsage::default();
+    let captured_at = chrono::Local
+        .with_ymd_and_hms(2024, 1, 2, 3, 4, 5)
+        .single()
+        .expect("timestamp");
+    let snapshot = RateLimitSnapshot {
+        primary: Some(RateLimitWindow {
+            used_percent: 30.0,
+            window_minutes: Some(300),
+            resets_at: None,
+        }),
+        secondary: Some(RateLimitWindow {
+            used_percent: 10.0,
+            window_minutes: Some(10_080),
+            resets_at: None,
+        }),
+    };
+    let rate_display = rate_limit_snapshot_display(&snapshot, captured_at);
+
+    let mut limit_labels = |glyphs: LimitGlyphs| {
+        config.tui_limit_glyphs = glyphs;
+        let composite = new_status_output(
+            &config,
+            &usage,
+            Some(&usage),
+            &None,
+            Some(&rate_display),
+            &StatusSessionDetails::default(),
+            captured_at,
+        );
+        render_lines(&composite.display_lines(120))
+            .into_iter()
+            .filter_map(|line| {
+                let label_end = line.find(" limit:")?;
+                let label_start = line.find("│  ")? + "│  ".len();
+                Some(line[label_start..label_end].to_string())
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        limit_labels(LimitGlyphs::Unicode),
+        vec!["◷ 5h".to_string(), "▦ Weekly".to_string()]
+    );
+    assert_eq!(
+        limit_labels(LimitGlyphs::Ascii),
+        vec!["o 5h".to_string(), "# Weekly".to_string()]
+    );
+    assert_eq!(
+        limit_labels(LimitGlyphs::None),
+        vec!["5h".to_string(), "Weekly".to_string()]
+    );
+}
+
+#[test]
+fn model_display_keeps_explicit_reasoning_effort() {
+    let temp_home = TempDir::new().expect("temp home");
+    let mut config = test_config(&temp_home);
+    config.model = "gpt-5-codex".to_string();
+    let entries = vec![("reasoning effort", "High".to_string())];
+
+    assert_eq!(
+        compose_model_display(&config, &entries, None),
+        (
+            "gpt-5-codex".to_string(),
+            vec!["reasoning high".to_string()]
+        )
+    );
+}
+
+#[test]
+fn model_display_falls_back_to_default_reasoning_effort() {
+    let temp_home = TempDir::new().expect("temp home");
+    let mut config = test_config(&temp_home);
+    config.model = "gpt-5-codex".to_string();
+    config.model_reasoning_effort = None;
+
+    assert_eq!(
+        compose_model_display(&config, &[], None),
+        (
+            "gpt-5-codex".to_string(),
+            vec!["reasoning medium (default)".to_string()]
+        )
+    );
+}
+
+#[test]
+fn model_display_omits_reasoning_effort_for_unknown_model() {
+    let temp_home = TempDir::new().expect("temp home");
+    let mut config = test_config(&temp_home);
+    config.model = "custom-model".to_string();
+    config.model_reasoning_effort = None;
+
+    assert_eq!(
+        compose_model_display(&config, &[], None),
+        ("custom-model".to_string(), Vec::<String>::new())
+    );
+}
+
+#[test]
+fn model_display_includes_compact_context_usage() {
+    let temp_home = TempDir::new().expect("temp home");
+    let mut config = test_config(&temp_home);
+    config.model = "gpt-5-codex".to_string();
+    config.model_context_window = Some(200_000);
+    let usage = TokenUsage {
+        total_tokens: 42_000,
+        ..TokenUsage::default()
+    };
+    let entries = vec![("reasoning effort", "high".to_string())];
+
+    assert_eq!(
+        compose_model_display(&config, &entries, Some(&usage)),
+        (
+            "gpt-5-codex".to_string(),
+            vec![
+                "reasoning high".to_string(),
+                "context 42K / 200K (21%)".to_string()
+            ]
+        )
+    );
+}
+
+#[test]
+fn model_display_omits_context_when_usage_unknown() {
+    let temp_home = TempDir::new().expect("temp home");
+    let mut config = test_config(&temp_home);
+    config.model = "gpt-5-codex".to_string();
+    config.model_context_window = Some(200_000);
+    let entries = vec![("reasoning effort", "high".to_string())];
+
+    assert_eq!(
+        compose_model_display(&config, &entries, None),
+        (
+            "gpt-5-codex".to_string(),
+            vec!["reasoning high".to_string()]
+        )
+    );
+}
+
+#[test]
+fn summaries_state_parses_known_and_custom_values() {
+    assert_eq!(SummariesState::parse("none"), Some(SummariesState::Off));
+    assert_eq!(SummariesState::parse(" Off "), Some(SummariesState::Off));
+    assert_eq!(SummariesState::parse("auto"), Some(SummariesState::Auto));
+    assert_eq!(
+        SummariesState::parse("Detailed"),
+        Some(SummariesState::Detailed)
+    );
+    assert_eq!(
+        SummariesState::parse("concise"),
+        Some(SummariesState::Other("concise".to_string()))
+    );
+    assert_eq!(SummariesState::parse("  "), None);
+}
+
+#[test]
+fn model_display_with_summaries_returns_typed_state() {
+    let temp_home = TempDir::new().expect("temp home");
+    let mut config = test_config(&temp_home);
+    config.model = "gpt-5-codex".to_string();
+    let entries = vec![
+        ("reasoning effort", "high".to_string()),
+        ("reasoning summaries", "none".to_string()),
+    ];
+
+    assert_eq!(
+        compose_model_display_with_summaries(&config, &entries, None, None),
+        (
+            "gpt-5-codex".to_string(),
+            vec!["reasoning high".to_string(), "summaries off".to_string()],
+            Some(SummariesState::Off)
+        )
+    );
+}
+
+#[test]
+fn model_display_applies_reasoning_effort_aliases() {
+    let temp_home = TempDir::new().expect("temp home");
+    let mut config = test_config(&temp_home);
+    config.model = "gpt-5-codex".to_string();
+    let aliases: HashMap<String, String> = [("high", "hi"), ("minimal", "min")]
+        .into_iter()
+        .map(|(effort, alias)| (effort.to_string(), alias.to_string()))
+        .collect();
+    let entries = vec![("reasoning effort", "High".to_string())];
+
+    assert_eq!(
+        compose_model_display_with_summaries(&config, &entries, None, Some(&aliases)),
+        (
+            "gpt-5-codex".to_string(),
+            vec!["reasoning hi".to_string()],
+            None
+        )
+    );
+
+    // Efforts without an alias, including the fallback default, keep their
+    // lowercase name.
+    config.model_reasoning_effort = None;
+    assert_eq!(
+        compose_model_display_with_summaries(&config, &[], None, Some(&aliases)),
+        (
+            "gpt-5-codex".to_string(),
+            vec!["reasoning medium (default)".to_string()],
+            None
+        )
+    );
+}
+
+#[test]
+fn model_display_without_aliases_uses_lowercase_effort() {
+    let temp_home = TempDir::new().expect("temp home");
+    let mut config = test_config(&temp_home);
+    config.model = "gpt-5-codex".to_string();
+    let entries = vec![("reasoning effort", "Minimal".to_string())];
+
+    assert_eq!(
+        compose_model_display_with_summaries(&config, &entries, None, None),
+        (
+            "gpt-5-codex".to_string(),
+            vec!["reasoning minimal".to_string()],
+            None
+        )
+    );
+}
+
+#[test]
+fn account_display_distinguishes_missing_valid_and_corrupt_auth() {
+    let temp_home = TempDir::new().expect("temp home");
+    let config = test_config(&temp_home);
+    let auth_path = temp_home.path().join("auth.json");
+
+    let missing = compose_account_display(&config);
+    std::fs::write(&auth_path, r#"{"OPENAI_API_KEY":"sk-test"}"#).expect("write auth.json");
+    let valid = compose_account_display(&config);
+    std::fs::write(&auth_path, r#"{"OPENAI_API_KEY":"#).expect("write auth.json");
+    let corrupt = compose_account_display(&config);
+
+    assert_eq!(
+        (missing, valid, corrupt),
+        (
+            None,
+            Some(StatusAccountDisplay::ApiKey),
+            Some(StatusAccountDisplay::Unknown {
+                reason: "credentials could not be parsed".to_string(),
+            }),
+        )
+    );
+}
+
+#[test]
+fn account_displays_list_api_key_and_chatgpt_with_api_key_active() {
+    let temp_home = TempDir::new().expect("temp home");
+    let config = test_config(&temp_home);
+    // Payload: {"email":"user@example.com","https://api.openai.com/auth":{"chatgpt_plan_type":"pro"}}
+    let id_token = "e30.eyJlbWFpbCI6InVzZXJAZXhhbXBsZS5jb20iLCJodHRwczovL2FwaS5vcGVuYWkuY29tL2F1dGgiOnsiY2hhdGdwdF9wbGFuX3R5cGUiOiJwcm8ifX0.sig";
+    let auth = serde_json::json!({
+        "OPENAI_API_KEY": "sk-test",
+        "tokens": {
+            "id_token": id_token,
+            "access_token": "access",
+            "refresh_token": "refresh",
+            "account_id": null,
+        },
+    });
+    std::fs::write(temp_home.path().join("auth.json"), auth.to_string()).expect("write auth.json");
+
+    let chatgpt = StatusAccountDisplay::ChatGpt {
+        email: Some("user@example.com".to_string()),
+        plan: Some("Pro".to_string()),
+    };
+    assert_eq!(
+        compose_account_displays(&config),
+        vec![
+            StatusAccountEntry {
+                account: StatusAccountDisplay::ApiKey,
+                active: true,
+            },
+            StatusAccountEntry {
+                account: chatgpt,
+                active: false,
+            },
+        ]
+    );
+    assert_eq!(
+        compose_account_display(&config),
+        Some(StatusAccountDisplay::ApiKey)
+    );
+}
+
+#[test]
+fn status_card_lists_inactive_chatgpt_login_under_api_key() {
+    let temp_home = TempDir::new().expect("temp home");
+    let mut config = test_config(&temp_home);
+    config.cwd = PathBuf::from("/workspace/tests");
+    // Payload: {"email":"user@example.com","https://api.openai.com/auth":{"chatgpt_plan_type":"pro"}}
+    let id_token = "e30.eyJlbWFpbCI6InVzZXJAZXhhbXBsZS5jb20iLCJodHRwczovL2FwaS5vcGVuYWkuY29tL2F1dGgiOnsiY2hhdGdwdF9wbGFuX3R5cGUiOiJwcm8ifX0.sig";
+    let auth = serde_json::json!({
+        "OPENAI_API_KEY": "sk-test",
+        "tokens": {
+            "id_token": id_token,
+            "access_token": "access",
+            "refresh_token": "refresh",
+            "account_id": null,
+        },
+    });
+    std::fs::write(temp_home.path().join("auth.json"), auth.to_string()).expect("write auth.json");
+    let now = chrono::Local
+        .with_ymd_and_hms(2024, 2, 3, 4, 5, 6)
+        .single()
+        .expect("timestamp");
+
+    let composite = new_status_output(
+        &config,
+        &TokenUsage::default(),
+        None,
+        &None,
+        None,
+        &StatusSessionDetails::default(),
+        now,
+    );
+    let rendered = render_lines(&composite.display_lines(100));
+    let account_idx = rendered
+        .iter()
+        .position(|line| line.contains("Account:"))
+        .expect("account row");
+    let normalize = |line: &str| {
+        line.trim_matches(|c| c == '│' || c == ' ')
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    assert_eq!(
+        rendered[account_idx..account_idx + 2]
+            .iter()
+            .map(|line| normalize(line))
+            .collect::<Vec<_>>(),
+        vec![
+            "Account: API key configured (used before ChatGPT)",
+            "user@example.com (Pro) (inactive)",
+        ]
+    );
+}
+
+#[test]
+fn status_json_serializes_full_status() {
+    let temp_home = TempDir::new().expect("temp home");
+    std::fs::write(
+        temp_home.path().join("auth.json"),
+        r#"{"OPENAI_API_KEY":"sk-test"}"#,
+    )
+    .expect("write auth.json");
+    let workspace = TempDir::new().expect("workspace");
+    let mut config = test_config(&temp_home);
+    config.cwd = workspace.path().to_path_buf();
+    config.model = "gpt-5-codex".to_string();
+    config.model_reasoning_effort = Some(ReasoningEffort::High);
+    config.model_reasoning_summary = ReasoningSummary::Detailed;
+    config.model_context_window = Some(272_000);
+    config.sandbox_policy = SandboxPolicy::DangerFullAccess;
+    let usage = TokenUsage {
+        total_tokens: 12_500,
+        ..TokenUsage::default()
+    };
+    let total_usage = TokenUsage {
+        input_tokens: 1_200,
+        cached_input_tokens: 200,
+        output_tokens: 900,
+        reasoning_output_tokens: 0,
+        total_tokens: 2_100,
+    };
+    let session_id =
+        ConversationId::from_string("0199a213-81c0-7800-8aa1-bbab2a035a53").expect("session id");
+    let captured_at = Utc
+        .with_ymd_and_hms(2024, 1, 2, 3, 4, 5)
+        .single()
+        .expect("timestamp")
+        .with_timezone(&chrono::Local);
+    let rate_limits = RateLimitSnapshotDisplay {
+        captured_at,
+        primary: Some(RateLimitWindowDisplay {
+            used_percent: 72.5,
+            resets_at: Some("03:14".to_string()),
+            window_minutes: Some(300),
+            reset_after: Some(std::time::Duration::from_secs(600)),
+        }),
+        secondary: Some(RateLimitWindowDisplay {
+            used_percent: 40.0,
+            resets_at: None,
+            window_minutes: Some(10_080),
+            reset_after: None,
+        }),
+    };
+
+    assert_eq!(
+        status_json(
+            &config,
+            &total_usage,
+            Some(&usage),
+            &Some(session_id),
+            Some(&rate_limits)
+        ),
+        serde_json::json!({
+            "model": "gpt-5-codex",
+            "model_details": [
+                "reasoning high",
+                "summaries detailed",
+                "context 12.5K / 272K (5%)"
+            ],
+            "directory": workspace.path(),
+            "approval": config.approval_policy.to_string(),
+            "sandbox": "danger-full-access",
+            "agents_summary": "<none>",
+            "account": { "type": "api_key" },
+            "session_id": "0199a213-81c0-7800-8aa1-bbab2a035a53",
+            "token_usage": { "total": 1_900, "input": 1_000, "output": 900 },
+            "rate_limits": {
+                "captured_at": "2024-01-02T03:04:05Z",
+                "primary": {
+                    "used_percent": 72.5,
+                    "resets_at": "03:14",
+                    "window_minutes": 300,
+                    "resets_in_seconds": 600
+                },
+                "secondary": {
+                    "used_percent": 40.0,
+                    "resets_at": null,
+                    "window_minutes": 10_080,
+                    "resets_in_seconds": null
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn status_json_serializes_minimal_status() {
+    let temp_home = TempDir::new().expect("temp home");
+    let workspace = TempDir::new().expect("workspace");
+    let mut config = test_config(&temp_home);
+    config.cwd = workspace.path().to_path_buf();
+    config.model = "gpt-5-codex".to_string();
+    config.model_reasoning_effort = Some(ReasoningEffort::Low);
+
+    assert_eq!(
+        status_json(&config, &TokenUsage::default(), None, &None, None),
+        serde_json::json!({
+            "model": "gpt-5-codex",
+            "model_details": ["reasoning low", "summaries auto"],
+            "directory": workspace.path(),
+            "approval": config.approval_policy.to_string(),
+            "sandbox": "read-only",
+            "agents_summary": "<none>",
+            "account": null,
+            "session_id": null,
+            "token_usage": { "total": 0, "input": 0, "output": 0 },
+            "rate_limits": null
+        })
+    );
+}
+
+#[test]
+fn status_json_output_renders_pretty_printed_json() {
+    let temp_home = TempDir::new().expect("temp home");
+    let workspace = TempDir::new().expect("workspace");
+    let mut config = test_config(&temp_home);
+    config.cwd = workspace.path().to_path_buf();
+    config.model = "gpt-5-codex".to_string();
+    config.model_reasoning_effort = Some(ReasoningEffort::Low);
+
+    let usage = TokenUsage::default();
+    let cell = new_status_json_output(&config, &usage, None, &None, None);
+    let rendered = render_lines(&cell.display_lines(20)).join("\n");
+
+    let value = status_json(&config, &usage, None, &None, None);
+    let expected = serde_json::to_string_pretty(&value).expect("serialize status JSON");
+    assert_eq!(rendered, expected);
+    let parsed: serde_json::Value = serde_json::from_str(&rendered).expect("valid JSON");
+    assert_eq!(parsed, value);
+}
+
+#[test]
+fn long_email_is_center_truncated_keeping_tld() {
+    let email = "firstname.lastname@really-long-subdomain.example.com";
+
+    let truncated = format_email_display(email, Some(20));
+
+    assert_eq!(truncated, "firstname.…ample.com");
+    assert_eq!(truncated.chars().count(), 20);
+}
+
+#[test]
+fn short_email_is_untouched() {
+    assert_eq!(
+        format_email_display("me@example.com", Some(20)),
+        "me@example.com"
+    );
+}
+
+#[test]
+fn title_case_words_capitalizes_each_word() {
+    assert_eq!(title_case_words("team pro"), "Team Pro");
+    assert_eq!(title_case_words("  ENTERPRISE   plus "), "Enterprise Plus");
+}
+
+#[test]
+fn title_case_words_matches_title_case_for_single_words() {
+    for plan in ["plus", "PRO", "business", ""] {
+        assert_eq!(title_case_words(plan), title_case(plan));
+    }
+}
+
+fn reset_in_after(seconds: Option<i64>) -> Option<std::time::Duration> {
+    let captured_at = chrono::Local
+        .with_ymd_and_hms(2024, 1, 2, 3, 4, 5)
+        .single()
+        .expect("timestamp");
+    let snapshot = RateLimitSnapshot {
+        primary: Some(RateLimitWindow {
+            used_percent: 50.0,
+            window_minutes: Some(300),
+            resets_at: seconds,
+        }),
+        secondary: None,
+    };
+    rate_limit_snapshot_display(&snapshot, captured_at)
+        .primary
+        .and_then(|window| window.reset_in())
+}
+
+#[test]
+fn reset_in_measures_from_capture_time() {
+    let captured_at = chrono::Local
+        .with_ymd_and_hms(2024, 1, 2, 3, 4, 5)
+        .single()
+        .expect("timestamp");
+
+    for minutes in [30, 120, 1_440] {
+        assert_eq!(
+            reset_in_after(Some(reset_at_from(&captured_at, minutes * 60))),
+            Some(std::time::Duration::from_secs((minutes * 60) as u64))
+        );
+    }
+}
+
+#[test]
+fn reset_in_is_none_for_unknown_or_invalid_resets() {
+    let captured_at = chrono::Local
+        .with_ymd_and_hms(2024, 1, 2, 3, 4, 5)
+        .single()
+        .expect("timestamp");
+
+    assert_eq!(reset_in_after(None), None);
+    assert_eq!(reset_in_after(Some(i64::MAX)), None);
+    assert_eq!(reset_in_after(Some(reset_at_from(&captured_at, -60))), None);
+}
+
+#[test]
+fn agents_display_path_relates_docs_to_cwd() {
+    let sep = std::path::MAIN_SEPARATOR;
+    let cwd = Path::new("/workspaces/codex/codex-rs/tui");
+
+    assert_eq!(
+        [
+            agents_display_path(&cwd.join("AGENTS.md"), cwd),
+            agents_display_path(Path::new("/workspaces/codex/AGENTS.md"), cwd),
+            agents_display_path(&cwd.join("src").join("AGENTS.md"), cwd),
+            agents_display_path(Path::new("/srv/docs/AGENTS.md"), cwd),
+        ],
+        [
+            "AGENTS.md".to_string(),
+            format!("..{sep}..{sep}AGENTS.md"),
+            format!("src{sep}AGENTS.md"),
+            Path::new("/srv/docs/AGENTS.md").display().to_string(),
+        ]
+    );
+}
+
+#[test]
+fn git_relative_directory_display_uses_repo_name() {
+    let sep = std::path::MAIN_SEPARATOR;
+    let git_root = Path::new("/workspaces/codex");
+    let dir = git_root.join("codex-rs").join("tui");
+
+    assert_eq!(
+        format_directory_display_git_relative(&dir, git_root, None),
+        format!("codex{sep}codex-rs{sep}tui")
+    );
+    assert_eq!(
+        format_directory_display_git_relative(git_root, git_root, None),
+        "codex"
+    );
+}
+
+#[test]
+fn git_relative_directory_display_falls_back_outside_repo() {
+    let git_root = Path::new("/workspaces/codex");
+    let dir = Path::new("/srv/other-project");
+
+    assert_eq!(
+        format_directory_display_git_relative(dir, git_root, None),
+        format_directory_display(dir, None)
+    );
+}
+
+#[test]
+fn git_relative_directory_display_center_truncates() {
+    let git_root = Path::new("/workspaces/codex");
+    let dir = git_root
+        .join("codex-rs")
+        .join("tui")
+        .join("src")
+        .join("bottom_pane");
+
+    let sep = std::path::MAIN_SEPARATOR;
+
+    assert_eq!(
+        format_directory_display_git_relative(&dir, git_root, Some(24)),
+        format!("codex{sep}…{sep}src{sep}bottom_pane")
+    );
+}
+
+fn config_with_mcp_servers(temp_home: &TempDir, names: &[&str]) -> Config {
+    let mut config = test_config(temp_home);
+    for name in names {
+        config.mcp_servers.insert(
+            name.to_string(),
+            McpServerConfig {
+                transport: McpServerTransportConfig::Stdio {
+                    command: "echo".to_string(),
+                    args: Vec::new(),
+                    env: None,
+                    env_vars: Vec::new(),
+                    cwd: None,
+                },
+                enabled: true,
+                startup_timeout_sec: None,
+                tool_timeout_sec: None,
+                enabled_tools: None,
+                disabled_tools: None,
+            },
+        );
+    }
+    config
+}
+
+fn mcp_statuses(connected: &[&str]) -> Vec<McpServerStatus> {
+    connected
+        .iter()
+        .map(|name| McpServerStatus {
+            name: name.to_string(),
+            connected: true,
+        })
+        .collect()
+}
+
+#[test]
+fn mcp_summary_falls_back_when_nothing_connected() {
+    let temp_home = TempDir::new().expect("temp home");
+    let config = config_with_mcp_servers(&temp_home, &["docs"]);
+    let statuses = vec![McpServerStatus {
+        name: "docs".to_string(),
+        connected: false,
+    }];
+
+    assert_eq!(compose_mcp_summary(&config, &statuses), "<none>");
+    assert_eq!(compose_mcp_summary(&config, &[]), "<none>");
+}
+
+#[test]
+fn mcp_summary_lists_single_connected_server() {
+    let temp_home = TempDir::new().expect("temp home");
+    let config = config_with_mcp_servers(&temp_home, &["docs", "search"]);
+
+    assert_eq!(
+        compose_mcp_summary(&config, &mcp_statuses(&["docs", "unconfigured"])),
+        "1 connected: docs"
+    );
+}
+
+#[test]
+fn mcp_summary_truncates_long_lists() {
+    let temp_home = TempDir::new().expect("temp home");
+    let names = ["github", "docs", "search", "figma", "linear"];
+    let config = config_with_mcp_servers(&temp_home, &names);
+
+    assert_eq!(
+        compose_mcp_summary(&config, &mcp_statuses(&["github", "docs", "search"])),
+        "3 connected: docs, github, search"
+    );
+    assert_eq!(
+        compose_mcp_summary(&config, &mcp_statuses(&names)),
+        "5 connected: docs, figma, github, +2 more"
+    );
+}
+
+#[test]
+fn status_card_shows_mcp_row_once_servers_are_configured() {
+    let temp_home = TempDir::new().expect("temp home");
+    let mut config = config_with_mcp_servers(&temp_home, &["docs", "search"]);
+    config.cwd = PathBuf::from("/workspace/tests");
+    let now = chrono::Local
+        .with_ymd_and_hms(2024, 1, 1, 0, 0, 0)
+        .single()
+        .expect("timestamp");
+    let mcp_row = |session: StatusSessionDetails| {
+        let composite = new_status_output(
+            &config,
+            &TokenUsage::default(),
+            None,
+            &None,
+            None,
+            &session,
+            now,
+        );
+        render_lines(&composite.display_lines(120))
+            .into_iter()
+            .find(|line| line.contains("MCP servers:"))
+            .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+    };
+
+    let unchecked = mcp_row(StatusSessionDetails::default());
+    let checked = mcp_row(StatusSessionDetails {
+        mcp_servers: Some(mcp_statuses(&["docs"])),
+        ..Default::default()
+    });
+
+    assert_eq!(
+        (unchecked, checked),
+        (
+            Some("│ MCP servers: not checked yet (run /mcp) │".to_string()),
+            Some("│ MCP servers: 1 connected: docs │".to_string()),
+        )
+    );
+}
+
+fn render_sparkline(history: &[f64], width: u16) -> String {
+    let area = Rect::new(0, 0, width, 1);
+    let mut buf = Buffer::empty(area);
+    UsageSparkline::new(history).render_ref(area, &mut buf);
+    (0..width).map(|x| buf[(x, 0)].symbol()).collect()
+}
+
+#[test]
+fn usage_sparkline_renders_rising_trend() {
+    assert_eq!(
+        render_sparkline(&[10.0, 30.0, 50.0, 70.0, 90.0], 8),
+        "▂▃▅▆▇   "
+    );
+}
+
+#[test]
+fn usage_sparkline_keeps_most_recent_points() {
+    assert_eq!(render_sparkline(&[0.0, 100.0, 0.0, 100.0], 3), "█▁█");
+}
+
+#[test]
+fn usage_sparkline_needs_two_points() {
+    assert_eq!(render_sparkline(&[80.0], 4), "    ");
+    assert_eq!(render_sparkline(&[], 4), "    ");
+}
+
+#[test]
+fn status_card_shows_usage_trend_below_limits() {
+    let temp_home = TempDir::new().expect("temp home");
+    let mut config = test_config(&temp_home);
+    config.cwd = PathBuf::from("/workspace/tests");
+    let now = chrono::Local
+        .with_ymd_and_hms(2024, 1, 1, 0, 0, 0)
+        .single()
+        .expect("timestamp");
+    let trend_row = |usage_history: Vec<f64>| {
+        let composite = new_status_output(
+            &config,
+            &TokenUsage::default(),
+            None,
+            &None,
+            None,
+            &StatusSessionDetails {
+                usage_history,
+                ..Default::default()
+            },
+            now,
+        );
+        render_lines(&composite.display_lines(80))
+            .into_iter()
+            .find(|line| line.contains("Usage trend:"))
+            .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+    };
+
+    assert_eq!(
+        trend_row(vec![10.0, 30.0, 50.0, 70.0, 90.0]),
+        Some("│ Usage trend: ▂▃▅▆▇ │".to_string())
+    );
+    assert_eq!(trend_row(vec![80.0]), None);
+}
+
+fn git(repo: &Path, args: &[&str]) {
+    let status = std::process::Command::new("git")
+        .env("GIT_CONFIG_GLOBAL", "/dev/null")
+        .env("GIT_CONFIG_NOSYSTEM", "1")
+        .args(args)
+        .current_dir(repo)
+        .status()
+        .expect("run git");
+    assert!(status.success(), "git {args:?} failed");
+}
+
+#[test]
+fn git_summary_reports_branch_and_staged_change() {
+    let repo = TempDir::new().expect("repo");
+    git(repo.path(), &["init", "-q"]);
+    git(repo.path(), &["symbolic-ref", "HEAD", "refs/heads/feature"]);
+    std::fs::write(repo.path().join("file.txt"), "hello").expect("write file");
+    git(repo.path(), &["add", "file.txt"]);
+
+    assert_eq!(
+        compose_git_summary(repo.path()),
+        Some(GitSummary {
+            branch: Some("feature".to_string()),
+            ahead: 0,
+            behind: 0,
+            dirty: true,
+        })
+    );
+}
+
+#[test]
+fn git_summary_is_none_outside_a_repo() {
+    let dir = TempDir::new().expect("dir");
+
+    assert_eq!(compose_git_summary(dir.path()), None);
+}
+
+#[test]
+fn porcelain_v2_status_parses_upstream_divergence() {
+    let status = "# branch.oid 0123abcd\n\
+                  # branch.head main\n\
+                  # branch.upstream origin/main\n\
+                  # branch.ab +2 -3\n";
+
+    assert_eq!(
+        parse_porcelain_v2_status(status),
+        GitSummary {
+            branch: Some("main".to_string()),
+            ahead: 2,
+            behind: 3,
+            dirty: false,
+        }
+    );
+}
+
+#[test]
+fn porcelain_v2_status_treats_detached_head_as_no_branch() {
+    let status = "# branch.oid 0123abcd\n# branch.head (detached)\n1 .M N... 100644 100644 100644 a b f.txt\n";
+
+    assert_eq!(
+        parse_porcelain_v2_status(status),
+        GitSummary {
+            branch: None,
+            ahead: 0,
+            behind: 0,
+            dirty: true,
+        }
+    );
+}
+
+#[test]
+fn git_summary_formats_branch_divergence_and_dirty_state() {
+    let summary = |branch: Option<&str>, ahead, behind, dirty| GitSummary {
+        branch: branch.map(str::to_string),
+        ahead,
+        behind,
+        dirty,
+    };
+
+    assert_eq!(
+        vec![
+            format_git_summary(&summary(Some("main"), 0, 0, false)),
+            format_git_summary(&summary(Some("main"), 1, 2, true)),
+            format_git_summary(&summary(None, 0, 0, true)),
+        ],
+        vec![
+            "main".to_string(),
+            "main (ahead 1, behind 2, dirty)".to_string(),
+            "detached HEAD (dirty)".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn status_card_shows_git_row_below_directory() {
+    let temp_home = TempDir::new().expect("temp home");
+    let mut config = test_config(&temp_home);
+    config.cwd = PathBuf::from("/workspace/tests");
+    let now = chrono::Local
+        .with_ymd_and_hms(2024, 1, 1, 0, 0, 0)
+        .single()
+        .expect("timestamp");
+
+    let composite = new_status_output(
+        &config,
+        &TokenUsage::default(),
+        None,
+        &None,
+        None,
+        &StatusSessionDetails {
+            git: Some(GitSummary {
+                branch: Some("main".to_string()),
+                ahead: 1,
+                behind: 0,
+                dirty: true,
+            }),
+            ..Default::default()
+        },
+        now,
+    );
+    let rendered = render_lines(&composite.display_lines(120));
+
+    let directory_row = rendered
+        .iter()
+        .position(|line| line.contains("Directory:"))
+        .expect("directory row");
+    assert_eq!(
+        rendered[directory_row + 1]
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" "),
+        "│ Git: main (ahead 1, dirty) │"
+    );
+}
+
+#[test]
+fn status_card_shows_directory_relative_to_git_root() {
+    let temp_home = TempDir::new().expect("temp home");
+    let workspace = TempDir::new().expect("workspace");
+    let repo = workspace.path().join("codex");
+    std::fs::create_dir_all(repo.join(".git")).expect("create .git");
+    std::fs::create_dir_all(repo.join("tui")).expect("create subdir");
+    let mut config = test_config(&temp_home);
+    config.cwd = repo.join("tui");
+    let now = chrono::Local
+        .with_ymd_and_hms(2024, 1, 1, 0, 0, 0)
+        .single()
+        .expect("timestamp");
+
+    let composite = new_status_output(
+        &config,
+        &TokenUsage::default(),
+        None,
+        &None,
+        None,
+        &StatusSessionDetails {
+            git: Some(GitSummary {
+                branch: Some("main".to_string()),
+                ahead: 0,
+                behind: 0,
+                dirty: false,
+            }),
+            ..Default::default()
+        },
+        now,
+    );
+    let rendered = render_lines(&composite.display_lines(120));
+
+    let directory_row = rendered
+        .iter()
+        .find(|line| line.contains("Directory:"))
+        .expect("directory row");
+    assert_eq!(
+        directory_row
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" "),
+        format!("│ Directory: codex{}tui │", std::path::MAIN_SEPARATOR)
+    );
+}
+
+#[test]
+fn session_elapsed_formats_across_unit_boundaries() {
+    let start = Instant::now();
+    let elapsed = |secs: u64| format_session_elapsed(start, start + Duration::from_secs(secs));
+
+    assert_eq!(
+        vec![
+            elapsed(0),
+            elapsed(59),
+            elapsed(60),
+            elapsed(3 * 60 + 59),
+            elapsed(59 * 60 + 59),
+            elapsed(60 * 60),
+            elapsed(65 * 60),
+            elapsed(24 * 60 * 60 - 1),
+            elapsed(24 * 60 * 60),
+            elapsed((2 * 24 + 4) * 60 * 60 + 30 * 60),
+        ],
+        vec![
+            "<1m", "<1m", "1m", "3m", "59m", "1h", "1h 5m", "23h 59m", "1d", "2d 4h"
+        ]
+    );
+}
+
+#[test]
+fn session_elapsed_is_zero_when_now_precedes_start() {
+    let now = Instant::now();
+    assert_eq!(
+        format_session_elapsed(now + Duration::from_secs(120), now),
+        "<1m"
+    );
+}
+
+fn pricing_table() -> HashMap<String, ModelPricing> {
+    HashMap::from([(
+        "gpt-5-codex".to_string(),
+        ModelPricing {
+            input_per_million_tokens: 1.25,
+            output_per_million_tokens: 10.0,
+        },
+    )])
+}
+
+#[test]
+fn estimate_cost_formats_dollar_amount_from_pricing() {
+    let pricing = ModelPricing {
+        input_per_million_tokens: 1.25,
+        output_per_million_tokens: 10.0,
+    };
+
+    assert_eq!(
+        vec![
+            estimate_cost(2_000_000, 150_000, &pricing),
+            estimate_cost(0, 0, &pricing),
+            estimate_cost(1_000, 0, &pricing),
+        ],
+        vec!["$4.00", "$0.00", "<$0.01"]
+    );
+}
+
+#[test]
+fn status_card_shows_estimated_cost_only_for_priced_models() {
+    let temp_home = TempDir::new().expect("temp home");
+    let mut config = test_config(&temp_home);
+    config.tui_model_pricing = pricing_table();
+    let usage = TokenUsage {
+        input_tokens: 2_000_000,
+        cached_input_tokens: 0,
+        output_tokens: 150_000,
+        reasoning_output_tokens: 0,
+        total_tokens: 2_150_000,
+    };
+    let now = chrono::Local
+        .with_ymd_and_hms(2024, 2, 3, 4, 5, 6)
+        .single()
+        .expect("timestamp");
+    let cost_lines = |config: &Config| {
+        let composite = new_status_output(
+            config,
+            &usage,
+            Some(&usage),
+            &None,
+            None,
+            &StatusSessionDetails::default(),
+            now,
+        );
+        render_lines(&composite.display_lines(80))
+            .into_iter()
+            .filter(|line| line.contains("Est. cost"))
+            .map(|line| {
+                line.trim_matches(|c| c == '│' || c == ' ')
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>()
+    };
+
+    config.model = "gpt-5-codex".to_string();
+    assert_eq!(cost_lines(&config), vec!["Est. cost: $4.00"]);
+
+    config.model = "custom-model".to_string();
+    assert_eq!(cost_lines(&config), Vec::<String>::new());
+}
+
+fn window_display(used_percent: f64, window_minutes: i64) -> RateLimitWindowDisplay {
+    RateLimitWindowDisplay {
+        used_percent,
+        resets_at: None,
+        window_minutes: Some(window_minutes),
+        reset_after: None,
+    }
+}
+
+fn merge_fixture() -> (RateLimitSnapshotDisplay, chrono::DateTime<chrono::Local>) {
+    let captured_at = chrono::Local
+        .with_ymd_and_hms(2024, 1, 2, 3, 4, 5)
+        .single()
+        .expect("timestamp");
+    let existing = RateLimitSnapshotDisplay::builder(captured_at)
+        .primary(10.0, None, Some(300))
+        .secondary(20.0, None, Some(10_080))
+        .build();
+    (existing, captured_at + ChronoDuration::minutes(1))
+}
+
+#[test]
+fn merge_with_primary_only_update_keeps_secondary() {
+    let (existing, updated_at) = merge_fixture();
+    let update = RateLimitSnapshotDisplay {
+        captured_at: updated_at,
+        primary: Some(window_display(55.0, 300)),
+        secondary: None,
+    };
+
+    assert_eq!(
+        existing.merge(&update),
+        RateLimitSnapshotDisplay {
+            captured_at: updated_at,
+            primary: Some(window_display(55.0, 300)),
+            secondary: Some(window_display(20.0, 10_080)),
+        }
+    );
+}
+
+#[test]
+fn merge_with_secondary_only_update_keeps_primary() {
+    let (existing, updated_at) = merge_fixture();
+    let update = RateLimitSnapshotDisplay {
+        captured_at: updated_at,
+        primary: None,
+        secondary: Some(window_display(65.0, 10_080)),
+    };
+
+    assert_eq!(
+        existing.merge(&update),
+        RateLimitSnapshotDisplay {
+            captured_at: updated_at,
+            primary: Some(window_display(10.0, 300)),
+            secondary: Some(window_display(65.0, 10_080)),
+        }
+    );
+}
+
+fn window_resetting_in(used_percent: f64, minutes: Option<u64>) -> RateLimitWindowDisplay {
+    RateLimitWindowDisplay {
+        reset_after: minutes.map(|minutes| Duration::from_secs(minutes * 60)),
+        ..window_display(used_percent, 300)
+    }
+}
+
+#[test]
+fn most_urgent_window_breaks_ties_by_sooner_reset() {
+    let (mut snapshot, _) = merge_fixture();
+    snapshot.primary = Some(window_resetting_in(80.0, Some(120)));
+    snapshot.secondary = Some(window_resetting_in(80.0, Some(30)));
+
+    assert_eq!(
+        snapshot.most_urgent_window(),
+        Some(&window_resetting_in(80.0, Some(30)))
+    );
+}
+
+#[test]
+fn most_urgent_window_prefers_primary_when_resets_are_unknown() {
+    let (mut snapshot, _) = merge_fixture();
+    snapshot.primary = Some(window_resetting_in(80.0, None));
+    snapshot.secondary = Some(window_resetting_in(80.0, Some(30)));
+    assert_eq!(
+        snapshot.most_urgent_window(),
+        Some(&window_resetting_in(80.0, None))
+    );
+
+    snapshot.secondary = Some(window_resetting_in(90.0, None));
+    assert_eq!(
+        snapshot.most_urgent_window(),
+        Some(&window_resetting_in(90.0, None))
+    );
+}
+
+#[test]
+fn usage_line_shows_compact_counts_and_percent() {
+    assert_eq!(format_usage_line(128_000, 200_000), "128K / 200K (64%)");
+    assert_eq!(format_usage_line(1_500, 1_000_000), "1.5K / 1M (0%)");
+    assert_eq!(format_usage_line(250_000, 200_000), "250K / 200K (125%)");
+}
+
+#[test]
+fn usage_line_with_zero_limit_shows_used_count_only() {
+    assert_eq!(format_usage_line(42_000, 0), "42K");
+}
+
+#[test]
+fn rate_limit_transition_reports_significant_change() {
+    let (existing, updated_at) = merge_fixture();
+    let updated = RateLimitSnapshotDisplay {
+        captured_at: updated_at,
+        primary: Some(window_display(72.0, 300)),
+        ..existing
+    };
+    let next = RateLimitSnapshotDisplay {
+        primary: Some(window_display(79.0, 300)),
+        ..updated.clone()
+    };
+
+    assert_eq!(
+        describe_rate_limit_transition(Some(&updated), Some(&next)),
+        "primary 72% -> 79% significant change; secondary unchanged at 20%"
+    );
+}
+
+#[test]
+fn rate_limit_transition_reports_minor_change_and_edges() {
+    let (existing, updated_at) = merge_fixture();
+    let next = RateLimitSnapshotDisplay {
+        captured_at: updated_at,
+        primary: Some(window_display(12.0, 300)),
+        secondary: None,
+    };
+
+    assert_eq!(
+        describe_rate_limit_transition(Some(&existing), Some(&next)),
+        "primary 10% -> 12% minor change; secondary 20% -> absent"
+    );
+    assert_eq!(
+        describe_rate_limit_transition(None, Some(&existing)),
+        "first snapshot: primary 10%, secondary 20%"
+    );
+    assert_eq!(
+        describe_rate_limit_transition(Some(&existing), None),
+        "cleared"
+    );
+}
+
+#[test]
+fn compact_status_line_drops_sections_as_width_shrinks() {
+    let directory = Path::new("/workspace/projects/codex");
+    let line = |width| compose_compact_status_line("gpt-5-codex", directory, Some(72.4), width);
+
+    assert_eq!(
+        [line(80), line(50)],
+        [
+            "gpt-5-codex · /workspace/projects/codex · 72% used".to_string(),
+            "gpt-5-codex · /workspace/projects/codex · 72% used".to_string(),
+        ]
+    );
+    assert_eq!(
+        line(40),
+        format!(
+            "gpt-5-codex · {} · 72% used",
+            format_directory_display(directory, Some(15))
+        )
+    );
+    assert_eq!(line(30), "gpt-5-codex · 72% used");
+    assert_eq!(line(15), "gpt-5-codex");
+    assert_eq!(line(8), "gpt-5...");
+}
+
+#[test]
+fn compact_status_line_without_usage_keeps_model_and_directory() {
+    let directory = Path::new("/workspace/projects/codex");
+
+    assert_eq!(
+        compose_compact_status_line("gpt-5-codex", directory, None, 80),
+        "gpt-5-codex · /workspace/projects/codex"
+    );
+    assert_eq!(
+        compose_compact_status_line("gpt-5-codex", directory, None, 20),
+        "gpt-5-codex"
+    );
+}
+
+#[test]
+fn status_card_collapses_to_compact_line_in_very_narrow_terminal() {
+    let temp_home = TempDir::new().expect("temp home");
+    let mut config = test_config(&temp_home);
+    config.model = "gpt-5-codex".to_string();
+    config.cwd = PathBuf::from("/workspace/tests");
+    let now = chrono::Local
+        .with_ymd_and_hms(2024, 1, 1, 0, 0, 0)
+        .single()
+        .expect("timestamp");
+    let snapshot = RateLimitSnapshot {
+        primary: Some(RateLimitWindow {
+            used_percent: 12.0,
+            window_minutes: Some(300),
+            resets_at: None,
+        }),
+        secondary: Some(RateLimitWindow {
+            used_percent: 72.4,
+            window_minutes: Some(10_080),
+            resets_at: None,
+        }),
+    };
+    let rate_display = rate_limit_snapshot_display(&snapshot, now);
+
+    let composite = new_status_output(
+        &config,
+        &TokenUsage::default(),
+        None,
+        &None,
+        Some(&rate_display),
+        &StatusSessionDetails::default(),
+        now,
+    );
+
+    assert_eq!(
+        render_lines(&composite.display_lines(30)),
+        vec![
+            "/status".to_string(),
+            String::new(),
+            "gpt-5-codex · 72% used".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn rate_limit_snapshot_display_round_trips_through_json() {
+    let (mut snapshot, _) = merge_fixture();
+    snapshot.primary = Some(RateLimitWindowDisplay {
+        resets_at: Some("03:09".to_string()),
+        ..window_display(42.0, 300)
+    });
+    snapshot.secondary = Some(RateLimitWindowDisplay {
+        window_minutes: None,
+        ..window_display(7.5, 10_080)
+    });
+
+    let value = serde_json::to_value(&snapshot).expect("serialize snapshot");
+    assert_eq!(
+        value,
+        serde_json::json!({
+            "captured_at": serde_json::to_value(snapshot.captured_at).expect("serialize timestamp"),
+            "primary": {
+                "used_percent": 42.0,
+                "resets_at": "03:09",
+                "window_minutes": 300,
+            },
+            "secondary": {
+                "used_percent": 7.5,
+                "resets_at": null,
+                "window_minutes": null,
+            },
+        })
+    );
+
+    let restored: RateLimitSnapshotDisplay =
+        serde_json::from_value(value).expect("deserialize snapshot");
+    assert_eq!(restored, snapshot);
+}
+
+#[test]
+fn rate_limit_snapshot_builder_matches_literal() {
+    let (_, captured_at) = merge_fixture();
+
+    assert_eq!(
+        RateLimitSnapshotDisplay::builder(captured_at)
+            .primary(42.0, Some("03:09".to_string()), Some(300))
+            .build(),
+        RateLimitSnapshotDisplay {
+            captured_at,
+            primary: Some(RateLimitWindowDisplay {
+                used_percent: 42.0,
+                resets_at: Some("03:09".to_string()),
+                window_minutes: Some(300),
+                reset_after: None,
+            }),
+            secondary: None,
+        }
+    );
+}
+
+#[test]
+fn rate_limit_reset_detected_on_sharp_drop_with_later_reset() {
+    let (mut existing, updated_at) = merge_fixture();
+    existing.secondary = Some(window_resetting_in(90.0, Some(10)));
+    let reset = RateLimitSnapshotDisplay {
+        captured_at: updated_at,
+        primary: existing.primary.clone(),
+        secondary: Some(window_resetting_in(2.0, Some(10_080))),
+    };
+
+    assert_eq!(
+        detect_rate_limit_reset(&existing, &reset),
+        Some(RateLimitWindowKind::Secondary)
+    );
+}
+
+#[test]
+fn rate_limit_reset_ignores_fluctuation_and_unmoved_reset() {
+    let (mut existing, updated_at) = merge_fixture();
+    existing.primary = Some(window_resetting_in(90.0, Some(120)));
+    let fluctuation = RateLimitSnapshotDisplay {
+        captured_at: updated_at,
+        primary: Some(window_resetting_in(60.0, Some(119))),
+        ..existing.clone()
+    };
+    // A sharp drop whose reset time stayed put is not a fresh window.
+    let same_reset = RateLimitSnapshotDisplay {
+        captured_at: updated_at,
+        primary: Some(window_resetting_in(10.0, Some(119))),
+        ..existing.clone()
+    };
+
+    assert_eq!(
+        (
+            detect_rate_limit_reset(&existing, &fluctuation),
+            detect_rate_limit_reset(&existing, &same_reset)
+        ),
+        (None, None)
+    );
+}
+
+#[test]
+fn field_formatter_aligns_values_after_wide_labels() {
+    let formatter = FieldFormatter::from_labels(["Model", "模型名称"]);
+    let value_column = |label: &'static str| {
+        let line = formatter.line(label, vec![Span::from("value")]);
+        let rendered = render_lines(&[line]).remove(0);
+        UnicodeWidthStr::width(&rendered[..rendered.find("value").expect("value")])
+    };
+
+    // "模型名称" is four characters but eight columns wide.
+    assert_eq!((value_column("Model"), value_column("模型名称")), (13, 13));
+}